@@ -0,0 +1,49 @@
+//! Benchmark comparing batch Keccak-256 hashing with hashing inputs one at a
+//! time, for inputs of equal and mixed lengths.
+//!
+//! Run with `cargo bench --features keccak --bench batch`.
+
+use ethdigest::Digest;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+/// The number of batches hashed per measurement.
+const ITERATIONS: usize = 20_000;
+
+fn main() {
+    let data = (0..=255).cycle().take(1024).collect::<Vec<u8>>();
+    for (name, lens) in [
+        ("equal 32 bytes", [32; 8]),
+        ("equal 500 bytes", [500; 8]),
+        (
+            "mixed 32-1000 bytes",
+            [32, 1000, 64, 500, 136, 32, 700, 100],
+        ),
+    ] {
+        let inputs = lens.map(|len| &data[..len]);
+        let serial = measure(|| inputs.map(|input| Digest::of(black_box(input))));
+        let batch = measure(|| Digest::of_batch(black_box(&inputs)));
+        println!(
+            "{name:>20}: serial {:>8.2?}, batch {:>8.2?} ({:.2}x)",
+            serial / ITERATIONS as u32,
+            batch / ITERATIONS as u32,
+            serial.as_secs_f64() / batch.as_secs_f64(),
+        );
+    }
+}
+
+/// Returns the best time out of several runs of hashing batches.
+fn measure(mut f: impl FnMut() -> [Digest; 8]) -> Duration {
+    (0..10)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..ITERATIONS {
+                black_box(f());
+            }
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
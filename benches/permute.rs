@@ -0,0 +1,63 @@
+//! Benchmark comparing the dependency-free Keccak-f\[1600\] permutation with
+//! the variant compiled with BMI1 and BMI2 instructions, which is selected at
+//! runtime on x86 CPUs that support them.
+//!
+//! Run with `cargo bench --bench permute`.
+
+// The `keccakf` module is shared with the `ethdigest` crate, and not all of
+// its functions are used by the benchmark.
+#[allow(dead_code)]
+#[path = "../src/keccakf.rs"]
+mod keccakf;
+
+use std::{hint::black_box, time::Instant};
+
+/// The number of permutations per measurement.
+const ITERATIONS: u32 = 200_000;
+
+#[inline(never)]
+fn portable(state: &mut [u64; 25]) {
+    *state = keccakf::f1600(*state);
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(never)]
+#[target_feature(enable = "bmi1,bmi2")]
+unsafe fn bmi(state: &mut [u64; 25]) {
+    *state = keccakf::f1600(*state);
+}
+
+fn main() {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if is_x86_feature_detected!("bmi1") && is_x86_feature_detected!("bmi2") {
+        // Interleave the measurements so that both variants are affected by
+        // frequency scaling and noisy neighbours in the same way.
+        let (mut portable_ns, mut bmi_ns) = (f64::MAX, f64::MAX);
+        for _ in 0..30 {
+            portable_ns = portable_ns.min(measure(portable));
+            // SAFETY: BMI1 and BMI2 support was checked above.
+            bmi_ns = bmi_ns.min(measure(|state| unsafe { bmi(state) }));
+        }
+        println!(
+            "portable {portable_ns:.1}ns, bmi {bmi_ns:.1}ns ({:.2}x)",
+            portable_ns / bmi_ns,
+        );
+        return;
+    }
+
+    println!(
+        "portable {:.1}ns",
+        (0..30).map(|_| measure(portable)).fold(f64::MAX, f64::min)
+    );
+}
+
+/// Returns the time in nanoseconds per permutation.
+fn measure(mut permute: impl FnMut(&mut [u64; 25])) -> f64 {
+    let mut state = [1; 25];
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        permute(black_box(&mut state));
+    }
+    black_box(state);
+    start.elapsed().as_secs_f64() * 1e9 / f64::from(ITERATIONS)
+}
@@ -0,0 +1 @@
+../../src/keccakf.rs
\ No newline at end of file
@@ -6,10 +6,17 @@
 
 extern crate proc_macro;
 
+// The `hex`, `keccakf` and `simd` modules are shared with the `ethdigest`
+// crate, and not all of their functions are used by the procedural macros.
+#[allow(dead_code)]
 mod hex;
+#[allow(dead_code)]
+mod keccakf;
+#[allow(dead_code)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+mod simd;
 
 use proc_macro::{Delimiter, Literal, Span, TokenStream, TokenTree};
-use sha3::{Digest as _, Keccak256};
 use std::fmt::Write as _;
 
 #[proc_macro]
@@ -48,10 +55,7 @@ impl DigestLiteral {
     fn generate_keccak(input: TokenStream) -> Result<Self, CompileError> {
         let input = Input::parse(input)?;
 
-        let mut hasher = Keccak256::new();
-        hasher.update(&input.value);
-
-        Ok(Self(hasher.finalize().into()))
+        Ok(Self(keccakf::keccak256(input.value.as_bytes())))
     }
 
     fn into_tokens(self) -> TokenStream {
@@ -0,0 +1 @@
+../../src/simd.rs
\ No newline at end of file
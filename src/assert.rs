@@ -0,0 +1,125 @@
+//! Module implementing test assertions for digests.
+
+use crate::Digest;
+use core::fmt;
+
+/// Asserts that two digests are equal.
+///
+/// On failure, this macro panics with both digests aligned one above the
+/// other, with a caret marking the first differing nibble and the index of the
+/// first differing byte. Like [`assert_eq!`], a custom panic message can be
+/// provided after the two digests.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{assert_digest_eq, Digest};
+/// let mut digest = Digest([0xee; 32]);
+/// assert_digest_eq!(digest, Digest([0xee; 32]));
+/// ```
+///
+/// Mismatched digests panic with a message pointing at the difference:
+///
+/// ```should_panic
+/// # use ethdigest::{assert_digest_eq, Digest};
+/// let mut digest = Digest([0xee; 32]);
+/// digest[3] = 0xef;
+/// assert_digest_eq!(digest, Digest([0xee; 32]), "digest was modified");
+/// ```
+#[macro_export]
+macro_rules! assert_digest_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left != *right {
+                    $crate::__assert_digest_failed(left, right, None);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if *left != *right {
+                    $crate::__assert_digest_failed(
+                        left,
+                        right,
+                        Some(format_args!($($arg)+)),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Panics with a message describing the difference between two digests.
+#[doc(hidden)]
+#[cold]
+#[track_caller]
+pub fn failed(left: &Digest, right: &Digest, args: Option<fmt::Arguments>) -> ! {
+    let byte = left
+        .iter()
+        .zip(right.iter())
+        .position(|(a, b)| a != b)
+        .unwrap_or_default();
+    let nibble = byte * 2 + usize::from(left[byte] >> 4 == right[byte] >> 4);
+
+    // Account for the `"  left: 0x"` prefix when aligning the caret.
+    let caret = 11 + nibble;
+    match args {
+        Some(args) => panic!(
+            "assertion `left == right` failed: {args}\n  left: {left}\n right: {right}\n\
+             {:>caret$} digests differ at byte {byte}",
+            "^",
+        ),
+        None => panic!(
+            "assertion `left == right` failed\n  left: {left}\n right: {right}\n\
+             {:>caret$} digests differ at byte {byte}",
+            "^",
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+
+    fn panic_message(f: impl FnOnce() + panic::UnwindSafe) -> String {
+        let payload = panic::catch_unwind(f).unwrap_err();
+        payload.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn equal_digests() {
+        assert_digest_eq!(Digest([0xee; 32]), Digest([0xee; 32]));
+        assert_digest_eq!(Digest::default(), Digest::default(), "unused {}", 42);
+    }
+
+    #[test]
+    fn marks_first_differing_nibble() {
+        let mut left = Digest([0xee; 32]);
+        left[1] = 0xef;
+        left[2] = 0x00;
+        let right = Digest([0xee; 32]);
+
+        assert_eq!(
+            panic_message(|| assert_digest_eq!(left, right)),
+            "assertion `left == right` failed\n  \
+             left: 0xeeef00eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee\n \
+             right: 0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee\n             \
+             ^ digests differ at byte 1",
+        );
+
+        left[1] = 0xee;
+        assert_eq!(
+            panic_message(|| assert_digest_eq!(left, right, "in {}", "test")),
+            "assertion `left == right` failed: in test\n  \
+             left: 0xeeee00eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee\n \
+             right: 0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee\n              \
+             ^ digests differ at byte 2",
+        );
+    }
+}
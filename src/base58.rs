@@ -0,0 +1,126 @@
+//! Internal module implementing base58 encoding and decoding with the Bitcoin
+//! alphabet.
+
+use core::str;
+
+/// The Bitcoin base58 alphabet.
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The capacity of the stack-allocated buffers used for encoding and decoding.
+/// This is large enough for any of the multiformat values that get encoded.
+const CAP: usize = 64;
+
+/// A stack-allocated buffer for base58 encoding and decoding.
+pub struct Buffer {
+    bytes: [u8; CAP],
+    len: usize,
+}
+
+impl Buffer {
+    /// Returns the buffered bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Returns the buffered base58 string.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Buffer should only ever contain characters from the base58
+        // alphabet when used for encoding.
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+}
+
+/// Encode bytes as a base58 string.
+///
+/// # Panics
+///
+/// Panics if the encoded string would not fit in the buffer. This never
+/// happens for inputs of up to 46 bytes.
+pub fn encode(bytes: &[u8]) -> Buffer {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+    // Compute the base58 digits in little-endian order.
+    let mut digits = [0_u8; CAP];
+    let mut len = 0;
+    for &byte in &bytes[zeros..] {
+        let mut carry = byte as u32;
+        for digit in &mut digits[..len] {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits[len] = (carry % 58) as u8;
+            len += 1;
+            carry /= 58;
+        }
+    }
+
+    let mut buffer = Buffer {
+        bytes: [b'1'; CAP],
+        len: zeros + len,
+    };
+    for (out, digit) in buffer.bytes[zeros..zeros + len]
+        .iter_mut()
+        .zip(digits[..len].iter().rev())
+    {
+        *out = ALPHABET[*digit as usize];
+    }
+    buffer
+}
+
+/// Decode a base58 string into bytes.
+pub fn decode(s: &str) -> Result<Buffer, DecodeError> {
+    let zeros = s.bytes().take_while(|&c| c == b'1').count();
+
+    // Compute the bytes in little-endian order.
+    let mut bytes = [0_u8; CAP];
+    let mut len = 0;
+    for (i, c) in s.bytes().enumerate().skip(zeros) {
+        let mut carry = digit(c).ok_or_else(|| DecodeError::InvalidCharacter {
+            c: s[i..].chars().next().unwrap(),
+            index: i,
+        })? as u32;
+        for byte in &mut bytes[..len] {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            if len == CAP {
+                return Err(DecodeError::Overflow);
+            }
+            bytes[len] = carry as u8;
+            len += 1;
+            carry >>= 8;
+        }
+    }
+    if zeros + len > CAP {
+        return Err(DecodeError::Overflow);
+    }
+
+    let mut buffer = Buffer {
+        bytes: [0; CAP],
+        len: zeros + len,
+    };
+    for (out, byte) in buffer.bytes[zeros..zeros + len]
+        .iter_mut()
+        .zip(bytes[..len].iter().rev())
+    {
+        *out = *byte;
+    }
+    Ok(buffer)
+}
+
+/// Returns the value of a base58 digit.
+fn digit(c: u8) -> Option<u8> {
+    ALPHABET.iter().position(|&d| d == c).map(|i| i as u8)
+}
+
+/// An error decoding a base58 string.
+pub enum DecodeError {
+    /// An invalid character was found.
+    InvalidCharacter { c: char, index: usize },
+    /// The decoded value does not fit in the buffer.
+    Overflow,
+}
@@ -0,0 +1,230 @@
+//! Module implementing base64 encoding and decoding of Ethereum 32-byte
+//! digests, as specified in RFC 4648.
+
+use crate::Digest;
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    str,
+};
+
+/// The standard base64 alphabet.
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The URL and filename safe base64 alphabet.
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// The length of an unpadded base64-encoded digest.
+const LEN: usize = 43;
+
+impl Digest {
+    /// Encodes the digest as a padded base64 string using the standard
+    /// alphabet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest([0xee; 32]).to_base64().as_str(),
+    ///     "7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u4=",
+    /// );
+    /// ```
+    pub fn to_base64(&self) -> Base64 {
+        encode(self, STANDARD, true)
+    }
+
+    /// Encodes the digest as an unpadded base64 string using the URL and
+    /// filename safe alphabet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest([0xff; 32]).to_base64url().as_str(),
+    ///     "__________________________________________8",
+    /// );
+    /// ```
+    pub fn to_base64url(&self) -> Base64 {
+        encode(self, URL_SAFE, false)
+    }
+
+    /// Parses a digest from a base64 string. Both the standard and the URL
+    /// and filename safe alphabets are accepted, with or without padding.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// for s in [
+    ///     "7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u4=",
+    ///     "7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u4",
+    /// ] {
+    ///     assert_eq!(Digest::from_base64(s).unwrap(), Digest([0xee; 32]));
+    /// }
+    /// ```
+    pub fn from_base64(s: &str) -> Result<Self, ParseBase64Error> {
+        let input = s.strip_suffix('=').unwrap_or(s).as_bytes();
+        if input.len() != LEN {
+            return Err(ParseBase64Error::InvalidLength);
+        }
+
+        let invalid_char = |index: usize| ParseBase64Error::InvalidCharacter {
+            c: s[index..].chars().next().unwrap(),
+            index,
+        };
+
+        // Accumulate 6-bit values and flush complete bytes.
+        let mut digest = Digest::default();
+        let (mut acc, mut bits, mut pos) = (0_u32, 0, 0);
+        for (index, &c) in input.iter().enumerate() {
+            let value = sextet(c).ok_or_else(|| invalid_char(index))?;
+            acc = (acc << 6) | u32::from(value);
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                digest[pos] = (acc >> bits) as u8;
+                pos += 1;
+            }
+        }
+
+        // The final character encodes 2 bits past the end of the digest,
+        // which must be zero for the encoding to be canonical.
+        if acc & 0b11 != 0 {
+            return Err(invalid_char(LEN - 1));
+        }
+        Ok(digest)
+    }
+}
+
+/// Encodes a digest with the specified alphabet.
+fn encode(digest: &Digest, alphabet: &[u8; 64], padding: bool) -> Base64 {
+    let mut buffer = [b'='; LEN + 1];
+    let (mut acc, mut bits, mut pos) = (0_u32, 0, 0);
+    for &byte in digest.iter() {
+        acc = (acc << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 6 {
+            bits -= 6;
+            buffer[pos] = alphabet[((acc >> bits) & 0x3f) as usize];
+            pos += 1;
+        }
+    }
+    buffer[pos] = alphabet[((acc << (6 - bits)) & 0x3f) as usize];
+
+    Base64 {
+        buffer,
+        len: LEN + usize::from(padding),
+    }
+}
+
+/// Returns the 6-bit value of a base64 character in either alphabet.
+fn sextet(c: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' | b'-' => Some(62),
+        b'/' | b'_' => Some(63),
+        _ => None,
+    }
+}
+
+/// A base64-encoded digest.
+///
+/// This is created with [`Digest::to_base64`] or [`Digest::to_base64url`].
+pub struct Base64 {
+    buffer: [u8; LEN + 1],
+    len: usize,
+}
+
+impl Base64 {
+    /// Returns the encoded digest as a string.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The buffer only contains ASCII characters.
+        unsafe { str::from_utf8_unchecked(&self.buffer[..self.len]) }
+    }
+}
+
+impl Debug for Base64 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Base64 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Represents an error parsing a base64 encoded digest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseBase64Error {
+    /// An invalid base64 character was found.
+    InvalidCharacter { c: char, index: usize },
+    /// The encoded value does not have the correct length.
+    InvalidLength,
+}
+
+impl Display for ParseBase64Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter { c, index } => {
+                write!(f, "invalid base64 character `{c}` at position {index}")
+            }
+            Self::InvalidLength => write!(f, "invalid encoded length"),
+        }
+    }
+}
+
+impl core::error::Error for ParseBase64Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_round_trip() {
+        let digest = Digest([
+            0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe,
+            0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef,
+            0xca, 0xfe, 0xba, 0xbe,
+        ]);
+        let standard = digest.to_base64();
+        assert_eq!(
+            standard.as_str(),
+            "3q2+78r+ur7erb7vyv66vt6tvu/K/rq+3q2+78r+ur4=",
+        );
+        let url = digest.to_base64url();
+        assert_eq!(url.as_str(), "3q2-78r-ur7erb7vyv66vt6tvu_K_rq-3q2-78r-ur4");
+        assert_eq!(Digest::from_base64(standard.as_str()).unwrap(), digest);
+        assert_eq!(Digest::from_base64(url.as_str()).unwrap(), digest);
+    }
+
+    #[test]
+    fn base64_errors() {
+        assert_eq!(
+            Digest::from_base64("7u7u"),
+            Err(ParseBase64Error::InvalidLength),
+        );
+        assert_eq!(
+            Digest::from_base64("7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u4=="),
+            Err(ParseBase64Error::InvalidLength),
+        );
+        assert_eq!(
+            Digest::from_base64("7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u!="),
+            Err(ParseBase64Error::InvalidCharacter { c: '!', index: 42 }),
+        );
+        assert_eq!(
+            Digest::from_base64("7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u7u5="),
+            Err(ParseBase64Error::InvalidCharacter { c: '5', index: 42 }),
+        );
+    }
+}
@@ -0,0 +1,395 @@
+//! Module implementing multi-buffer Keccak-256 hashing of batches of inputs.
+//!
+//! On x86 and x86-64 with AVX2, four inputs are hashed at a time in parallel
+//! lanes. On AArch64 with the SHA3 extension, two inputs are hashed at a time
+//! (plain NEON does not have enough of an edge over the scalar permutation to
+//! be worth it). Support is detected at runtime when the `std` feature is
+//! enabled, and at compile time otherwise. Other targets hash inputs one at a
+//! time.
+//!
+//! Lanes are absorbed in lockstep, so inputs are grouped by length, and groups
+//! whose lengths differ too much to benefit are hashed one at a time instead.
+//!
+//! The parallel lanes implement their own Keccak-f\[1600\] permutation, so
+//! they are only used with the built-in permutation. When another permutation
+//! backend is selected (`asm`, `rustcrypto` or `tiny-keccak`), inputs are
+//! hashed one at a time with that backend instead.
+
+use crate::Digest;
+
+impl Digest {
+    /// Creates digests by hashing a batch of inputs.
+    ///
+    /// Where supported, multiple inputs are hashed in parallel SIMD lanes,
+    /// which can be significantly faster than hashing them one at a time for
+    /// workloads with many small inputs (such as trie node hashing). The
+    /// speedup is best when inputs in a batch have similar lengths; inputs
+    /// that can't be grouped with others of similar length are hashed one at
+    /// a time.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let inputs: [&[u8]; 3] = [b"a", b"b", b"c"];
+    /// assert_eq!(
+    ///     Digest::of_batch(&inputs),
+    ///     [Digest::of("a"), Digest::of("b"), Digest::of("c")],
+    /// );
+    /// ```
+    #[doc(alias = "hash_batch")]
+    pub fn of_batch<const N: usize>(inputs: &[&[u8]; N]) -> [Digest; N] {
+        #[allow(unused_mut)]
+        let mut digests = [None; N];
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"),
+            not(any(feature = "asm", feature = "tiny-keccak", feature = "rustcrypto"))
+        ))]
+        lanes::hash(inputs, &mut digests);
+        core::array::from_fn(|i| digests[i].unwrap_or_else(|| Digest::of(inputs[i])))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"),
+    not(any(feature = "asm", feature = "tiny-keccak", feature = "rustcrypto"))
+))]
+mod lanes {
+    use crate::Digest;
+
+    /// The Keccak-256 sponge rate in bytes.
+    const RATE: usize = 136;
+
+    /// Hashes the inputs that benefit from parallel lanes on the current CPU,
+    /// leaving the remaining digests as `None`.
+    pub fn hash<const N: usize>(inputs: &[&[u8]; N], digests: &mut [Option<Digest>; N]) {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if avx2::available() {
+            // SAFETY: AVX2 support was checked.
+            group(inputs, digests, |inputs| unsafe { avx2::hash4(inputs) });
+        }
+        #[cfg(target_arch = "aarch64")]
+        if neon::available() {
+            // SAFETY: NEON and SHA3 extension support was checked.
+            group(inputs, digests, |inputs| unsafe { neon::hash2(inputs) });
+        }
+    }
+
+    /// Hashes groups of `L` inputs with similar lengths in parallel.
+    ///
+    /// A group takes as many permutations as its longest input has blocks, so
+    /// groups that would waste more than half of their lane permutations are
+    /// skipped, as they are faster to hash one at a time.
+    fn group<const L: usize, const N: usize>(
+        inputs: &[&[u8]; N],
+        digests: &mut [Option<Digest>; N],
+        hash: impl Fn(&[&[u8]; L]) -> [Digest; L],
+    ) {
+        let mut order: [usize; N] = core::array::from_fn(|i| i);
+        order.sort_unstable_by_key(|&i| inputs[i].len());
+        for group in order.chunks_exact(L) {
+            let group: [usize; L] = group.try_into().unwrap();
+            let blocks = group.map(|i| block_count(inputs[i]));
+            // Sorting by length means the last input has the most blocks.
+            if 2 * blocks.iter().sum::<usize>() < L * blocks[L - 1] {
+                continue;
+            }
+            let batch = hash(&group.map(|i| inputs[i]));
+            for (i, digest) in group.into_iter().zip(batch) {
+                digests[i] = Some(digest);
+            }
+        }
+    }
+
+    /// Returns the number of blocks that an input is absorbed in, including
+    /// the final padded block.
+    fn block_count(input: &[u8]) -> usize {
+        input.len() / RATE + 1
+    }
+
+    /// Returns a block of an input as little-endian lanes, applying Keccak
+    /// padding to the final block.
+    fn block(input: &[u8], index: usize) -> [u64; RATE / 8] {
+        let mut block = [0; RATE];
+        let data = &input[index * RATE..];
+        if data.len() >= RATE {
+            block.copy_from_slice(&data[..RATE]);
+        } else {
+            block[..data.len()].copy_from_slice(data);
+            block[data.len()] ^= 0x01;
+            block[RATE - 1] ^= 0x80;
+        }
+        let mut lanes = [0; RATE / 8];
+        for (lane, chunk) in lanes.iter_mut().zip(block.chunks_exact(8)) {
+            *lane = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        lanes
+    }
+
+    /// Absorbs inputs in lockstep, returning their digests. Interleaved lanes
+    /// are XOR-ed into the state and permuted with `absorb`, and the state of
+    /// each lane is read back with `squeeze`.
+    ///
+    /// Lanes that have already been squeezed keep getting permuted, but their
+    /// output is ignored.
+    #[inline(always)]
+    fn sponge<S, const L: usize>(
+        inputs: &[&[u8]; L],
+        mut state: S,
+        absorb: impl Fn(&mut S, &[[u64; L]; RATE / 8]),
+        squeeze: impl Fn(&S, usize) -> [u64; 4],
+    ) -> [Digest; L] {
+        let blocks = inputs.map(block_count);
+        let mut digests = [Digest::ZERO; L];
+        for index in 0..blocks.into_iter().max().unwrap_or(0) {
+            let mut lanes = [[0_u64; L]; RATE / 8];
+            for (n, input) in inputs.iter().enumerate() {
+                if index < blocks[n] {
+                    for (lane, word) in lanes.iter_mut().zip(block(input, index)) {
+                        lane[n] = word;
+                    }
+                }
+            }
+            absorb(&mut state, &lanes);
+
+            for (n, digest) in digests.iter_mut().enumerate() {
+                if index + 1 == blocks[n] {
+                    for (chunk, word) in digest.chunks_exact_mut(8).zip(squeeze(&state, n)) {
+                        chunk.copy_from_slice(&word.to_le_bytes());
+                    }
+                }
+            }
+        }
+        digests
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    mod avx2 {
+        use crate::{
+            keccakf::{PI, RC, RHO},
+            Digest,
+        };
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        /// Returns whether or not AVX2 multi-buffer hashing is available.
+        #[inline]
+        pub fn available() -> bool {
+            #[cfg(feature = "std")]
+            {
+                std::is_x86_feature_detected!("avx2")
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                cfg!(target_feature = "avx2")
+            }
+        }
+
+        /// Hashes four inputs in parallel.
+        ///
+        /// # Safety
+        ///
+        /// The caller must ensure that AVX2 is available.
+        #[target_feature(enable = "avx2")]
+        pub unsafe fn hash4(inputs: &[&[u8]; 4]) -> [Digest; 4] {
+            super::sponge(
+                inputs,
+                [_mm256_setzero_si256(); 25],
+                |state, lanes| {
+                    for (state, lane) in state.iter_mut().zip(lanes) {
+                        *state = _mm256_xor_si256(*state, _mm256_loadu_si256(lane.as_ptr().cast()));
+                    }
+                    f1600x4(state);
+                },
+                |state, n| {
+                    let mut words = [0; 4];
+                    for (word, state) in words.iter_mut().zip(state) {
+                        let mut lane = [0_u64; 4];
+                        _mm256_storeu_si256(lane.as_mut_ptr().cast(), *state);
+                        *word = lane[n];
+                    }
+                    words
+                },
+            )
+        }
+
+        /// Applies the Keccak-f\[1600\] permutation to four interleaved states.
+        #[target_feature(enable = "avx2")]
+        unsafe fn f1600x4(a: &mut [__m256i; 25]) {
+            for rc in RC {
+                // Theta
+                let mut c = [_mm256_setzero_si256(); 5];
+                for (x, c) in c.iter_mut().enumerate() {
+                    *c = _mm256_xor_si256(
+                        _mm256_xor_si256(_mm256_xor_si256(a[x], a[x + 5]), a[x + 10]),
+                        _mm256_xor_si256(a[x + 15], a[x + 20]),
+                    );
+                }
+                for x in 0..5 {
+                    let d = _mm256_xor_si256(c[(x + 4) % 5], rotate_left(c[(x + 1) % 5], 1));
+                    for y in (0..25).step_by(5) {
+                        a[y + x] = _mm256_xor_si256(a[y + x], d);
+                    }
+                }
+
+                // Rho and pi
+                let mut last = a[1];
+                for (&pi, &rho) in PI.iter().zip(&RHO) {
+                    let lane = a[pi];
+                    a[pi] = rotate_left(last, rho);
+                    last = lane;
+                }
+
+                // Chi
+                for y in (0..25).step_by(5) {
+                    let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+                    for x in 0..5 {
+                        a[y + x] = _mm256_xor_si256(
+                            row[x],
+                            _mm256_andnot_si256(row[(x + 1) % 5], row[(x + 2) % 5]),
+                        );
+                    }
+                }
+
+                // Iota
+                a[0] = _mm256_xor_si256(a[0], _mm256_set1_epi64x(rc as _));
+            }
+        }
+
+        /// Rotates each 64-bit lane left by the specified number of bits.
+        #[inline]
+        #[target_feature(enable = "avx2")]
+        unsafe fn rotate_left(value: __m256i, bits: u32) -> __m256i {
+            _mm256_or_si256(
+                _mm256_sll_epi64(value, _mm_cvtsi32_si128(bits as _)),
+                _mm256_srl_epi64(value, _mm_cvtsi32_si128(64 - bits as i32)),
+            )
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    mod neon {
+        use crate::{
+            keccakf::{PI, RC, RHO},
+            Digest,
+        };
+        use core::arch::aarch64::*;
+
+        /// Returns whether or not NEON multi-buffer hashing with the SHA3
+        /// extension is available.
+        #[inline]
+        pub fn available() -> bool {
+            #[cfg(feature = "std")]
+            {
+                std::arch::is_aarch64_feature_detected!("sha3")
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                cfg!(target_feature = "sha3")
+            }
+        }
+
+        /// Hashes two inputs in parallel.
+        ///
+        /// # Safety
+        ///
+        /// The caller must ensure that NEON and the SHA3 extension are
+        /// available.
+        #[target_feature(enable = "neon,sha3")]
+        pub unsafe fn hash2(inputs: &[&[u8]; 2]) -> [Digest; 2] {
+            super::sponge(
+                inputs,
+                [vdupq_n_u64(0); 25],
+                |state, lanes| {
+                    for (state, lane) in state.iter_mut().zip(lanes) {
+                        *state = veorq_u64(*state, vld1q_u64(lane.as_ptr()));
+                    }
+                    f1600x2(state);
+                },
+                |state, n| {
+                    let mut words = [0; 4];
+                    for (word, state) in words.iter_mut().zip(state) {
+                        let mut lane = [0_u64; 2];
+                        vst1q_u64(lane.as_mut_ptr(), *state);
+                        *word = lane[n];
+                    }
+                    words
+                },
+            )
+        }
+
+        /// Applies the Keccak-f\[1600\] permutation to two interleaved states.
+        #[target_feature(enable = "neon,sha3")]
+        unsafe fn f1600x2(a: &mut [uint64x2_t; 25]) {
+            for rc in RC {
+                // Theta
+                let mut c = [vdupq_n_u64(0); 5];
+                for (x, c) in c.iter_mut().enumerate() {
+                    *c = veor3q_u64(veor3q_u64(a[x], a[x + 5], a[x + 10]), a[x + 15], a[x + 20]);
+                }
+                for x in 0..5 {
+                    let d = vrax1q_u64(c[(x + 4) % 5], c[(x + 1) % 5]);
+                    for y in (0..25).step_by(5) {
+                        a[y + x] = veorq_u64(a[y + x], d);
+                    }
+                }
+
+                // Rho and pi
+                let mut last = a[1];
+                for (&pi, &rho) in PI.iter().zip(&RHO) {
+                    let lane = a[pi];
+                    a[pi] = rotate_left(last, rho);
+                    last = lane;
+                }
+
+                // Chi
+                for y in (0..25).step_by(5) {
+                    let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+                    for x in 0..5 {
+                        a[y + x] = vbcaxq_u64(row[x], row[(x + 2) % 5], row[(x + 1) % 5]);
+                    }
+                }
+
+                // Iota
+                a[0] = veorq_u64(a[0], vdupq_n_u64(rc));
+            }
+        }
+
+        /// Rotates each 64-bit lane left by the specified number of bits.
+        #[inline]
+        #[target_feature(enable = "neon")]
+        unsafe fn rotate_left(value: uint64x2_t, bits: u32) -> uint64x2_t {
+            // Negative shift amounts shift right.
+            vorrq_u64(
+                vshlq_u64(value, vdupq_n_s64(bits as _)),
+                vshlq_u64(value, vdupq_n_s64(bits as i64 - 64)),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::Digest as _;
+
+    #[test]
+    fn batch_matches_individual_hashing() {
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        let lens = [0, 1, 135, 136, 137, 271, 272, 1000, 64];
+        let inputs = lens.map(|len| &data[..len]);
+        assert_eq!(Digest::of_batch(&inputs), inputs.map(Digest::of));
+        assert_eq!(
+            Digest::of_batch(&inputs),
+            inputs.map(|input| Digest(sha3::Keccak256::digest(input).into())),
+        );
+
+        let inputs = [&data[..32]; 8];
+        assert_eq!(Digest::of_batch(&inputs), [Digest::of(&data[..32]); 8]);
+        assert_eq!(Digest::of_batch::<0>(&[]), [Digest::ZERO; 0]);
+    }
+}
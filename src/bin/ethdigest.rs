@@ -0,0 +1,203 @@
+//! Command line utility for hashing and formatting Ethereum digests.
+
+use ethdigest::{Digest, Keccak, PrefixPolicy, Sha3_256};
+use std::{
+    env,
+    fmt::Write as _,
+    fs::File,
+    io::{self, Read},
+    process::ExitCode,
+};
+
+const USAGE: &str = "\
+Usage: ethdigest <COMMAND> [ARGS]...
+
+Commands:
+  hash [--sha3] [FILE]...  Hash files (or stdin) with Keccak-256 or SHA3-256
+  parse <DIGEST>...        Parse and normalize hex digests
+  selector <SIGNATURE>     Compute a 4-byte function selector
+  topic <SIGNATURE>        Compute an event topic
+  namehash <NAME>          Compute an ENS namehash for a normalized name
+  address <PUBKEY>         Compute a checksummed address for a 64-byte public key
+  help                     Print this message
+";
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.first().map(String::as_str) {
+        Some("hash") => hash(&args[1..]),
+        Some("parse") => parse(&args[1..]),
+        Some("selector") => single(&args[1..]).map(|signature| {
+            let selector = Digest::of(signature).to_selector();
+            println!("0x{}", hex(&selector));
+        }),
+        Some("topic") => single(&args[1..]).map(|signature| {
+            println!("{}", Digest::of(signature));
+        }),
+        Some("namehash") => single(&args[1..]).map(|name| {
+            println!("{}", namehash(name));
+        }),
+        Some("address") => single(&args[1..]).and_then(|pubkey| {
+            println!("{}", address(pubkey)?);
+            Ok(())
+        }),
+        Some("help" | "-h" | "--help") => {
+            print!("{USAGE}");
+            Ok(())
+        }
+        _ => Err(format!("invalid command\n\n{USAGE}")),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn single(args: &[String]) -> Result<&str, String> {
+    match args {
+        [arg] => Ok(arg),
+        _ => Err("expected exactly one argument".to_owned()),
+    }
+}
+
+fn hash(args: &[String]) -> Result<(), String> {
+    let (sha3, files) = match args.first().map(String::as_str) {
+        Some("--sha3") => (true, &args[1..]),
+        _ => (false, args),
+    };
+    let digest = |reader: &mut dyn Read| {
+        if sha3 {
+            let mut hasher = Sha3_256::new();
+            io::copy(reader, &mut hasher)?;
+            Ok(hasher.finalize())
+        } else {
+            Digest::of_reader(reader)
+        }
+    };
+
+    if files.is_empty() {
+        let digest = digest(&mut io::stdin().lock()).map_err(|err: io::Error| err.to_string())?;
+        println!("{digest}");
+    }
+    for path in files {
+        let digest = File::open(path)
+            .and_then(|mut file| digest(&mut file))
+            .map_err(|err| format!("{path}: {err}"))?;
+        println!("{digest}  {path}");
+    }
+    Ok(())
+}
+
+fn parse(args: &[String]) -> Result<(), String> {
+    for arg in args {
+        let digest = Digest::from_str_lenient(arg).map_err(|err| format!("{arg}: {err}"))?;
+        println!("{digest}");
+    }
+    Ok(())
+}
+
+fn namehash(name: &str) -> Digest {
+    let mut node = Digest::ZERO;
+    if !name.is_empty() {
+        for label in name.rsplit('.') {
+            node = node.chain(&Digest::of(label));
+        }
+    }
+    node
+}
+
+fn address(pubkey: &str) -> Result<String, String> {
+    // A 64-byte public key is parsed as two 32-byte halves.
+    let pubkey = pubkey.strip_prefix("0x").unwrap_or(pubkey);
+    let half = |start: usize| {
+        let hex = pubkey
+            .get(start..start + 64)
+            .filter(|_| pubkey.len() == 128)?;
+        Digest::from_str_with(hex, PrefixPolicy::Forbidden).ok()
+    };
+    let (x, y) = half(0)
+        .zip(half(64))
+        .ok_or("expected a hex encoded 64-byte uncompressed public key")?;
+
+    let mut hasher = Keccak::new();
+    hasher.update(x);
+    hasher.update(y);
+    Ok(checksum(hasher.finalize().to_address_lossy()))
+}
+
+/// Returns the EIP-55 mixed-case checksum encoding of an address.
+fn checksum(address: [u8; 20]) -> String {
+    let address = hex(&address);
+    let checksum = Digest::of(&address);
+    let mut output = String::from("0x");
+    for (ch, nibble) in address.chars().zip(checksum.nibbles()) {
+        output.push(if nibble >= 8 {
+            ch.to_ascii_uppercase()
+        } else {
+            ch
+        });
+    }
+    output
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, byte| {
+        let _ = write!(s, "{byte:02x}");
+        s
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namehash_vectors() {
+        assert_eq!(namehash(""), Digest::ZERO);
+        assert_eq!(
+            namehash("eth"),
+            "0x93cdeb708b7545dc668eb9280176169d1c33cfd8ed6f04690a0bcc88a93fc4ae"
+                .parse::<Digest>()
+                .unwrap(),
+        );
+        assert_eq!(
+            namehash("foo.eth"),
+            "0xde9b09fd7c5f901e23a3f19fecc54828e9c848539801e86591bd9801b019f84f"
+                .parse::<Digest>()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn eip55_checksum() {
+        for address in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let digest = Digest::from_str_lenient(&format!("{:0>64}", &address[2..])).unwrap();
+            assert_eq!(checksum(digest.to_address_lossy()), address);
+        }
+    }
+
+    #[test]
+    fn address_from_pubkey() {
+        // The public key for the private key `1`.
+        let pubkey = "0x\
+            79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+            483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        assert_eq!(
+            address(pubkey).unwrap(),
+            "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+        );
+
+        let signed = format!("+f{}", &pubkey[4..]);
+        assert!(address(&signed).is_err());
+        assert!(address(&pubkey[..129]).is_err());
+    }
+}
@@ -0,0 +1,260 @@
+//! Module implementing bitwise operations on digests.
+
+use crate::Digest;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+impl Digest {
+    /// Returns the XOR distance between two digests, the metric used by
+    /// Kademlia-based Ethereum node discovery (discv4 and discv5).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let a = Digest::ZERO.with_byte(31, 0b0110);
+    /// let b = Digest::ZERO.with_byte(31, 0b0011);
+    /// assert_eq!(a.distance(&b), Digest::ZERO.with_byte(31, 0b0101));
+    /// ```
+    pub fn distance(&self, other: &Digest) -> Digest {
+        self ^ other
+    }
+
+    /// Returns the logarithmic distance between two digests, that is the bit
+    /// length of their XOR distance, or `None` if the digests are equal. This
+    /// is the distance used for Kademlia routing table buckets in Ethereum
+    /// node discovery, and is in the range `1..=256`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let a = Digest::ZERO;
+    /// assert_eq!(a.log2_distance(&a), None);
+    /// assert_eq!(a.log2_distance(&Digest::ZERO.with_byte(31, 1)), Some(1));
+    /// assert_eq!(a.log2_distance(&Digest::ZERO.with_byte(31, 0xff)), Some(8));
+    /// assert_eq!(a.log2_distance(&Digest::MAX), Some(256));
+    /// ```
+    pub fn log2_distance(&self, other: &Digest) -> Option<u32> {
+        let distance = self.distance(other);
+        (!distance.is_zero()).then(|| 256 - distance.leading_zero_bits())
+    }
+
+    /// Returns the bit at the specified index, where bits are indexed from
+    /// the most significant bit of the first byte. This matches the order in
+    /// which bit-paths are traversed in binary tries.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the index is not less than 256.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest::ZERO.with_byte(0, 0b0100_0000);
+    /// assert!(!digest.bit(0));
+    /// assert!(digest.bit(1));
+    /// ```
+    pub fn bit(&self, index: usize) -> bool {
+        assert!(index < 256, "bit index out of bounds");
+        self.0[index / 8] & (0x80 >> (index % 8)) != 0
+    }
+
+    /// Sets the bit at the specified index. See [`Digest::bit`] for how bits
+    /// are indexed.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the index is not less than 256.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let mut digest = Digest::ZERO;
+    /// digest.set_bit(255, true);
+    /// assert_eq!(digest, Digest::ZERO.with_byte(31, 1));
+    /// ```
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        assert!(index < 256, "bit index out of bounds");
+        let mask = 0x80 >> (index % 8);
+        if value {
+            self.0[index / 8] |= mask;
+        } else {
+            self.0[index / 8] &= !mask;
+        }
+    }
+
+    /// Returns the number of leading zero bits of the digest, interpreted as a
+    /// 256-bit big-endian integer. This is useful for proof-of-work style
+    /// difficulty checks.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::ZERO.leading_zero_bits(), 256);
+    /// assert_eq!(Digest::ZERO.with_byte(2, 0x10).leading_zero_bits(), 19);
+    /// ```
+    pub fn leading_zero_bits(&self) -> u32 {
+        let mut count = 0;
+        for limb in self.0.chunks_exact(8) {
+            let limb = u64::from_be_bytes(limb.try_into().unwrap());
+            count += limb.leading_zeros();
+            if limb != 0 {
+                break;
+            }
+        }
+        count
+    }
+
+    /// Returns the number of trailing zero bits of the digest, interpreted as
+    /// a 256-bit big-endian integer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::ZERO.trailing_zero_bits(), 256);
+    /// assert_eq!(Digest::ZERO.with_byte(29, 0x10).trailing_zero_bits(), 20);
+    /// ```
+    pub fn trailing_zero_bits(&self) -> u32 {
+        let mut count = 0;
+        for limb in self.0.rchunks_exact(8) {
+            let limb = u64::from_be_bytes(limb.try_into().unwrap());
+            count += limb.trailing_zeros();
+            if limb != 0 {
+                break;
+            }
+        }
+        count
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident) => {
+        impl $assign_op<&'_ Digest> for Digest {
+            fn $assign_method(&mut self, rhs: &'_ Digest) {
+                for (a, b) in self.0.iter_mut().zip(rhs.0) {
+                    a.$assign_method(b);
+                }
+            }
+        }
+
+        impl $assign_op for Digest {
+            fn $assign_method(&mut self, rhs: Digest) {
+                self.$assign_method(&rhs);
+            }
+        }
+
+        impl $op<&'_ Digest> for &'_ Digest {
+            type Output = Digest;
+
+            fn $method(self, rhs: &'_ Digest) -> Digest {
+                let mut result = *self;
+                result.$assign_method(rhs);
+                result
+            }
+        }
+
+        impl $op<Digest> for &'_ Digest {
+            type Output = Digest;
+
+            fn $method(self, rhs: Digest) -> Digest {
+                self.$method(&rhs)
+            }
+        }
+
+        impl $op<&'_ Digest> for Digest {
+            type Output = Digest;
+
+            fn $method(self, rhs: &'_ Digest) -> Digest {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl $op for Digest {
+            type Output = Digest;
+
+            fn $method(self, rhs: Digest) -> Digest {
+                (&self).$method(&rhs)
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
+impl Not for &'_ Digest {
+    type Output = Digest;
+
+    fn not(self) -> Digest {
+        Digest(self.0.map(|b| !b))
+    }
+}
+
+impl Not for Digest {
+    type Output = Digest;
+
+    fn not(self) -> Digest {
+        !&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitwise_operations() {
+        let a = Digest::repeat_byte(0b1100);
+        let b = Digest::repeat_byte(0b1010);
+
+        assert_eq!(a & b, Digest::repeat_byte(0b1000));
+        assert_eq!(a | b, Digest::repeat_byte(0b1110));
+        assert_eq!(a ^ b, Digest::repeat_byte(0b0110));
+        assert_eq!(!a, Digest::repeat_byte(!0b1100));
+
+        let (ra, rb) = (&a, &b);
+        assert_eq!(ra ^ rb, a ^ b);
+        assert_eq!(ra ^ b, a ^ rb);
+        assert_eq!(!ra, !a);
+
+        let mut c = a;
+        c ^= b;
+        c ^= rb;
+        assert_eq!(c, a);
+        c &= Digest::ZERO;
+        assert!(c.is_zero());
+        c |= &Digest::MAX;
+        assert_eq!(c, Digest::MAX);
+    }
+
+    #[test]
+    fn bit_accessors() {
+        let mut digest = Digest::ZERO;
+        for index in [0, 7, 8, 100, 255] {
+            digest.set_bit(index, true);
+            assert!(digest.bit(index));
+            assert_eq!(digest.count_ones(), 1);
+            assert_eq!(digest.leading_zero_bits(), index as u32);
+            assert_eq!(digest.trailing_zero_bits(), 255 - index as u32);
+            digest.set_bit(index, false);
+            assert!(digest.is_zero());
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Module implementing an stack-allocated formatting buffer for Ethereum
 //! digests.
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+use crate::simd;
 use core::{
     mem::{self, MaybeUninit},
     str,
@@ -17,16 +19,44 @@ pub fn fmt(bytes: &[u8; 32], alphabet: Alphabet) -> FormattingBuffer {
     buffer[0].write(b'0');
     buffer[1].write(b'x');
 
+    // SAFETY: The buffer has space for 64 bytes after the `0x` prefix.
+    unsafe { encode_raw(bytes, alphabet, buffer[2..].as_mut_ptr().cast()) };
+
+    let buffer = unsafe { mem::transmute::<[MaybeUninit<u8>; LEN], [u8; LEN]>(buffer) };
+    FormattingBuffer(buffer)
+}
+
+/// Encode digest bytes as hex into an output buffer, without a prefix.
+pub fn encode(bytes: &[u8; 32], alphabet: Alphabet, out: &mut [u8; 64]) {
+    // SAFETY: The output buffer is exactly 64 bytes long.
+    unsafe { encode_raw(bytes, alphabet, out.as_mut_ptr()) };
+}
+
+/// Encode digest bytes as hex, writing exactly 64 bytes to `out`.
+///
+/// # Safety
+///
+/// The caller must ensure that `out` is valid for writing 64 bytes.
+unsafe fn encode_raw(bytes: &[u8; 32], alphabet: Alphabet, out: *mut u8) {
     let lut = alphabet.lut();
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    if simd::available() {
+        // SAFETY: SIMD support was checked, and the caller guarantees that
+        // there is space for 64 bytes.
+        unsafe { simd::encode(bytes, lut, out) };
+        return;
+    }
+
     let nibble = |c: u8| lut[c as usize];
     for (i, byte) in bytes.iter().enumerate() {
-        let j = i * 2 + 2;
-        buffer[j].write(nibble(byte >> 4));
-        buffer[j + 1].write(nibble(byte & 0xf));
+        // SAFETY: `i * 2 + 1` is at most 63, which the caller guarantees is
+        // in bounds.
+        unsafe {
+            out.add(i * 2).write(nibble(byte >> 4));
+            out.add(i * 2 + 1).write(nibble(byte & 0xf));
+        }
     }
-
-    let buffer = unsafe { mem::transmute(buffer) };
-    FormattingBuffer(buffer)
 }
 
 /// A formatting buffer.
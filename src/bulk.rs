@@ -0,0 +1,258 @@
+//! Module implementing bulk parsing and formatting utilities for Ethereum
+//! 32-byte digests.
+
+#[cfg(feature = "std")]
+use crate::buffer::{self, Alphabet};
+use crate::{Digest, ParseDigestError};
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+#[cfg(feature = "std")]
+use std::{
+    borrow::Borrow,
+    fmt::Debug,
+    io::{self, BufRead, BufWriter, Write},
+};
+
+impl Digest {
+    /// Parses many hex digests, collecting them into a `Vec`.
+    ///
+    /// Parsing does not stop at the first error, instead all items are parsed
+    /// and the errors are reported along with the index of the item that
+    /// caused it. Note that JSON arrays of digests can be parsed by
+    /// deserializing a `Vec<Digest>` with the `serde` feature.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, ParseDigestError};
+    /// let digests = Digest::parse_many([
+    ///     "0x0000000000000000000000000000000000000000000000000000000000000000",
+    ///     "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(digests, [Digest([0; 32]), Digest([0xee; 32])]);
+    ///
+    /// let err = Digest::parse_many([
+    ///     "0x00",
+    ///     "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+    /// ])
+    /// .unwrap_err();
+    /// assert_eq!(
+    ///     err.errors(),
+    ///     [(0, ParseDigestError::InvalidLength { len: 4, expected: 66 })],
+    /// );
+    /// ```
+    #[doc(alias = "parse_all")]
+    #[doc(alias = "decode_many")]
+    pub fn parse_many<I>(items: I) -> Result<Vec<Self>, ParseManyError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let items = items.into_iter();
+        let mut digests = Vec::with_capacity(items.size_hint().0);
+        let mut errors = Vec::new();
+        for (index, item) in items.enumerate() {
+            match item.as_ref().parse() {
+                Ok(digest) => digests.push(digest),
+                Err(err) => errors.push((index, err)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(digests)
+        } else {
+            Err(ParseManyError { errors })
+        }
+    }
+
+    /// Parses newline-delimited hex digests from a reader. Empty lines are
+    /// skipped.
+    ///
+    /// Parsing errors are reported as [`io::ErrorKind::InvalidData`] errors
+    /// wrapping a [`ParseManyError`], where the indices are line numbers
+    /// starting from 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, ParseManyError};
+    /// let input = "\
+    ///     0x0000000000000000000000000000000000000000000000000000000000000000\n\
+    ///     \n\
+    ///     0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee\n\
+    /// ";
+    /// let digests = Digest::parse_lines(input.as_bytes()).unwrap();
+    /// assert_eq!(digests, [Digest([0; 32]), Digest([0xee; 32])]);
+    ///
+    /// let err = Digest::parse_lines("0x00\n".as_bytes()).unwrap_err();
+    /// let err = err.into_inner().unwrap().downcast::<ParseManyError>().unwrap();
+    /// assert_eq!(err.errors()[0].0, 0);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_lines(mut reader: impl BufRead) -> io::Result<Vec<Self>> {
+        let mut digests = Vec::new();
+        let mut errors = Vec::new();
+        let mut line = String::new();
+        for index in 0.. {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            let item = line.trim_end_matches(['\n', '\r']);
+            if item.is_empty() {
+                continue;
+            }
+            match item.parse() {
+                Ok(digest) => digests.push(digest),
+                Err(err) => errors.push((index, err)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(digests)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ParseManyError { errors },
+            ))
+        }
+    }
+}
+
+/// Represents errors parsing many digests.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseManyError {
+    errors: Vec<(usize, ParseDigestError)>,
+}
+
+impl ParseManyError {
+    /// Returns the parsing errors along with the index of the item that caused
+    /// them. There is always at least one error.
+    pub fn errors(&self) -> &[(usize, ParseDigestError)] {
+        &self.errors
+    }
+
+    /// Returns the parsing errors.
+    pub fn into_errors(self) -> Vec<(usize, ParseDigestError)> {
+        self.errors
+    }
+}
+
+impl Display for ParseManyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (index, err) = &self.errors[0];
+        write!(f, "invalid digest at index {index}: {err}")?;
+        if self.errors.len() > 1 {
+            write!(f, " (and {} more errors)", self.errors.len() - 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseManyError {}
+
+/// A buffered writer for exporting many digests as hex strings.
+///
+/// By default, digests are written `0x`-prefixed and separated by newlines.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, HexWriter};
+/// let mut writer = HexWriter::new(Vec::new())
+///     .with_prefix(false)
+///     .with_separator(",");
+/// writer.write_all([Digest([0x00; 32]), Digest([0xee; 32])]).unwrap();
+/// let output = writer.finish().unwrap();
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "0000000000000000000000000000000000000000000000000000000000000000,\
+///      eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+/// );
+/// ```
+#[cfg(feature = "std")]
+pub struct HexWriter<W: Write> {
+    inner: BufWriter<W>,
+    prefix: bool,
+    separator: String,
+    first: bool,
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> HexWriter<W> {
+    /// Creates a new hex writer wrapping the specified writer.
+    pub fn new(writer: W) -> Self {
+        Self {
+            inner: BufWriter::with_capacity(64 * 1024, writer),
+            prefix: true,
+            separator: "\n".to_owned(),
+            first: true,
+        }
+    }
+
+    /// Sets whether or not digests are written with a `0x` prefix.
+    pub fn with_prefix(mut self, prefix: bool) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Sets the separator that is written between digests.
+    pub fn with_separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Writes a single digest.
+    pub fn write(&mut self, digest: &Digest) -> io::Result<()> {
+        if !self.first {
+            self.inner.write_all(self.separator.as_bytes())?;
+        }
+        self.first = false;
+
+        let buffer = buffer::fmt(digest, Alphabet::default());
+        let hex = if self.prefix {
+            buffer.as_str()
+        } else {
+            buffer.as_bytes_str()
+        };
+        self.inner.write_all(hex.as_bytes())
+    }
+
+    /// Writes all digests from an iterator.
+    pub fn write_all<I>(&mut self, digests: I) -> io::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Digest>,
+    {
+        for digest in digests {
+            self.write(digest.borrow())?;
+        }
+        Ok(())
+    }
+
+    /// Flushes any buffered data and returns the underlying writer.
+    pub fn finish(self) -> io::Result<W> {
+        self.inner.into_inner().map_err(|err| err.into_error())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> Debug for HexWriter<W> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("HexWriter")
+            .field("prefix", &self.prefix)
+            .field("separator", &self.separator)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,30 @@
+//! Module implementing `bytemuck` traits for Ethereum 32-byte digests.
+
+use crate::Digest;
+use bytemuck::{Pod, Zeroable};
+
+// SAFETY: `Digest` is a `#[repr(transparent)]` wrapper around `[u8; 32]`,
+// which is `Zeroable`.
+unsafe impl Zeroable for Digest {}
+
+// SAFETY: `Digest` is a `#[repr(transparent)]` wrapper around `[u8; 32]`,
+// which is `Pod`.
+unsafe impl Pod for Digest {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cast_slices() {
+        let digests = [Digest([0xee; 32]), Digest([0xaa; 32])];
+        let bytes = bytemuck::cast_slice::<Digest, u8>(&digests);
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(bytes[..32], [0xee; 32]);
+        assert_eq!(bytes[32..], [0xaa; 32]);
+
+        assert_eq!(bytemuck::cast_slice::<u8, Digest>(bytes), digests);
+        assert!(bytemuck::try_cast_slice::<u8, Digest>(&bytes[1..]).is_err());
+        assert_eq!(Digest::zeroed(), Digest::ZERO);
+    }
+}
@@ -0,0 +1,156 @@
+//! Module implementing `clap` command line argument parsing for digests.
+//!
+//! This crate has no address or function selector types, so only digests and
+//! hash algorithms have value parsers.
+
+use crate::{Digest, ParseDigestError};
+#[cfg(feature = "keccak")]
+use clap::{builder::PossibleValue, ValueEnum};
+use clap::{
+    builder::{TypedValueParser, ValueParserFactory},
+    error::ErrorKind,
+    Arg, Command, Error,
+};
+use std::ffi::OsStr;
+
+/// A [`clap`](::clap) value parser for hex digests with an optional `0x`
+/// prefix.
+///
+/// Parsing errors include the offending argument and value, along with a
+/// suggestion for fixing common mistakes where available. This is the value
+/// parser used by `clap::value_parser!(Digest)`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Digest;
+/// # use clap::{value_parser, Arg, Command};
+/// let command = Command::new("example")
+///     .arg(Arg::new("digest").long("digest").value_parser(value_parser!(Digest)));
+///
+/// let matches = command
+///     .clone()
+///     .try_get_matches_from([
+///         "example",
+///         "--digest",
+///         "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+///     ])
+///     .unwrap();
+/// assert_eq!(
+///     matches.get_one::<Digest>("digest"),
+///     Some(&Digest([0xee; 32])),
+/// );
+///
+/// let typo = format!("0x{}O", "e".repeat(63));
+/// let err = command
+///     .try_get_matches_from(["example", "--digest", &typo])
+///     .unwrap_err();
+/// assert!(err.to_string().contains("replace `O` with `0`"));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DigestValueParser;
+
+impl TypedValueParser for DigestValueParser {
+    type Value = Digest;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Digest, Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+        value.parse().map_err(|err: ParseDigestError| {
+            let arg = arg
+                .map(ToString::to_string)
+                .unwrap_or_else(|| "...".to_owned());
+            Error::raw(
+                ErrorKind::ValueValidation,
+                format!("invalid value '{value}' for '{arg}': {err}\n"),
+            )
+            .with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for Digest {
+    type Parser = DigestValueParser;
+
+    fn value_parser() -> Self::Parser {
+        DigestValueParser
+    }
+}
+
+/// A hash algorithm producing 32-byte digests, selectable as a [`clap`](::clap)
+/// argument value.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, HashAlgorithm};
+/// # use clap::{value_parser, Arg, Command};
+/// let command = Command::new("example").arg(
+///     Arg::new("algorithm")
+///         .long("algorithm")
+///         .value_parser(value_parser!(HashAlgorithm))
+///         .default_value("keccak256"),
+/// );
+///
+/// let matches = command
+///     .try_get_matches_from(["example", "--algorithm", "sha3-256"])
+///     .unwrap();
+/// let algorithm = *matches.get_one::<HashAlgorithm>("algorithm").unwrap();
+/// assert_eq!(algorithm, HashAlgorithm::Sha3_256);
+/// assert_eq!(
+///     algorithm.digest("Hello Ethereum!"),
+///     ethdigest::Sha3_256::new().chain_update("Hello Ethereum!").finalize(),
+/// );
+/// ```
+#[cfg(feature = "keccak")]
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum HashAlgorithm {
+    /// Keccak-256, as used by Ethereum.
+    #[default]
+    Keccak256,
+    /// NIST SHA3-256.
+    Sha3_256,
+    /// SHA-256.
+    #[cfg(feature = "sha2")]
+    Sha256,
+}
+
+#[cfg(feature = "keccak")]
+impl HashAlgorithm {
+    /// Computes the digest of some data with the hash algorithm.
+    pub fn digest(self, data: impl AsRef<[u8]>) -> Digest {
+        match self {
+            Self::Keccak256 => Digest::of(data),
+            Self::Sha3_256 => crate::Sha3_256::new().chain_update(data).finalize(),
+            #[cfg(feature = "sha2")]
+            Self::Sha256 => Digest::sha256_of(data),
+        }
+    }
+}
+
+#[cfg(feature = "keccak")]
+impl ValueEnum for HashAlgorithm {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::Keccak256,
+            Self::Sha3_256,
+            #[cfg(feature = "sha2")]
+            Self::Sha256,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Keccak256 => PossibleValue::new("keccak256").alias("keccak-256"),
+            Self::Sha3_256 => PossibleValue::new("sha3-256").alias("sha3_256"),
+            #[cfg(feature = "sha2")]
+            Self::Sha256 => PossibleValue::new("sha256").alias("sha-256"),
+        })
+    }
+}
@@ -0,0 +1,425 @@
+//! Module implementing the cSHAKE and KMAC functions from NIST SP 800-185.
+
+use crate::{
+    shake::{ShakeReader, SHAKE_DOMAIN},
+    sponge::Sponge,
+};
+use core::fmt::{self, Debug, Formatter};
+
+/// The cSHAKE domain separation suffix and first padding bit.
+const CSHAKE_DOMAIN: u8 = 0x04;
+
+/// The KMAC function name used for domain separating cSHAKE.
+const KMAC: &[u8] = b"KMAC";
+
+/// Creates a cSHAKE sponge for a function name and customization string,
+/// returning it along with its domain separation suffix. This is plain SHAKE
+/// when both are empty.
+fn cshake<const RATE: usize>(name: &[u8], customization: &[u8]) -> (Sponge<RATE>, u8) {
+    let mut sponge = Sponge::new();
+    if name.is_empty() && customization.is_empty() {
+        return (sponge, SHAKE_DOMAIN);
+    }
+
+    left_encode(&mut sponge, RATE as u64);
+    encode_string(&mut sponge, name);
+    encode_string(&mut sponge, customization);
+    fill_block(&mut sponge);
+    (sponge, CSHAKE_DOMAIN)
+}
+
+/// Creates a KMAC sponge for a key and customization string.
+fn kmac<const RATE: usize>(key: &[u8], customization: &[u8]) -> Sponge<RATE> {
+    let (mut sponge, _) = cshake(KMAC, customization);
+    left_encode(&mut sponge, RATE as u64);
+    encode_string(&mut sponge, key);
+    fill_block(&mut sponge);
+    sponge
+}
+
+/// Absorbs an integer encoded with its length prefixed.
+fn left_encode<const RATE: usize>(sponge: &mut Sponge<RATE>, value: u64) {
+    let bytes = value.to_be_bytes();
+    let start = leading_zero_bytes(value);
+    sponge.update(&[(8 - start) as u8]);
+    sponge.update(&bytes[start..]);
+}
+
+/// Absorbs an integer encoded with its length suffixed.
+fn right_encode<const RATE: usize>(sponge: &mut Sponge<RATE>, value: u64) {
+    let bytes = value.to_be_bytes();
+    let start = leading_zero_bytes(value);
+    sponge.update(&bytes[start..]);
+    sponge.update(&[(8 - start) as u8]);
+}
+
+/// Returns the number of leading zero bytes to skip for the minimal
+/// big-endian encoding of an integer, which is at least one byte long.
+fn leading_zero_bytes(value: u64) -> usize {
+    (value.leading_zeros() / 8).min(7) as usize
+}
+
+/// Absorbs a bit string encoded with its bit length prefixed.
+fn encode_string<const RATE: usize>(sponge: &mut Sponge<RATE>, data: &[u8]) {
+    left_encode(sponge, data.len() as u64 * 8);
+    sponge.update(data);
+}
+
+/// Absorbs zeros until the pending input fills a complete block.
+fn fill_block<const RATE: usize>(sponge: &mut Sponge<RATE>) {
+    let pos = sponge.pos();
+    if pos > 0 {
+        sponge.update(&[0; RATE][pos..]);
+    }
+}
+
+macro_rules! impl_cshake {
+    ($name:ident, $rate:expr) => {
+        impl $name {
+            /// Creates a new hasher instance for a function name and
+            /// customization string. This is equivalent to SHAKE when both are
+            /// empty.
+            pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+                let (sponge, domain) = cshake(function_name, customization);
+                Self {
+                    sponge: sponge.clone(),
+                    initial: sponge,
+                    domain,
+                }
+            }
+
+            /// Processes new data and updates the hasher.
+            pub fn update(&mut self, data: impl AsRef<[u8]>) {
+                self.sponge.update(data.as_ref());
+            }
+
+            /// Processes new data and returns the updated hasher.
+            #[must_use]
+            pub fn chain_update(mut self, data: impl AsRef<[u8]>) -> Self {
+                self.update(data);
+                self
+            }
+
+            /// Finalizes the hasher and returns a reader for an arbitrary
+            /// amount of output.
+            pub fn finalize_xof(mut self) -> ShakeReader {
+                self.finalize_xof_reset()
+            }
+
+            /// Finalizes the hasher, returning a reader for an arbitrary amount
+            /// of output, and resets the hasher to its initial state so that it
+            /// can be reused.
+            pub fn finalize_xof_reset(&mut self) -> ShakeReader {
+                self.sponge.pad(self.domain);
+                let reader = ShakeReader::new(self.sponge.state, $rate);
+                self.reset();
+                reader
+            }
+
+            /// Resets the hasher to its initial state, discarding any processed
+            /// data but keeping its function name and customization string.
+            pub fn reset(&mut self) {
+                self.sponge = self.initial.clone();
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).finish()
+            }
+        }
+    };
+}
+
+/// A cSHAKE128 customizable extendable-output hasher.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::CShake128;
+/// let mut output = [0; 4];
+/// CShake128::new(b"", b"Email Signature")
+///     .chain_update([0x00, 0x01, 0x02, 0x03])
+///     .finalize_xof()
+///     .read(&mut output);
+/// assert_eq!(output, [0xc1, 0xc3, 0x69, 0x25]);
+/// ```
+#[derive(Clone)]
+pub struct CShake128 {
+    sponge: Sponge<168>,
+    initial: Sponge<168>,
+    domain: u8,
+}
+
+impl_cshake!(CShake128, 168);
+
+/// A cSHAKE256 customizable extendable-output hasher.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::CShake256;
+/// let mut output = [0; 4];
+/// CShake256::new(b"", b"Email Signature")
+///     .chain_update([0x00, 0x01, 0x02, 0x03])
+///     .finalize_xof()
+///     .read(&mut output);
+/// assert_eq!(output, [0xd0, 0x08, 0x82, 0x8e]);
+/// ```
+#[derive(Clone)]
+pub struct CShake256 {
+    sponge: Sponge<136>,
+    initial: Sponge<136>,
+    domain: u8,
+}
+
+impl_cshake!(CShake256, 136);
+
+macro_rules! impl_kmac {
+    ($name:ident, $rate:expr) => {
+        impl $name {
+            /// Creates a new MAC instance for a key and customization string.
+            pub fn new(key: &[u8], customization: &[u8]) -> Self {
+                let sponge = kmac(key, customization);
+                Self {
+                    sponge: sponge.clone(),
+                    initial: sponge,
+                }
+            }
+
+            /// Processes new data and updates the MAC.
+            pub fn update(&mut self, data: impl AsRef<[u8]>) {
+                self.sponge.update(data.as_ref());
+            }
+
+            /// Processes new data and returns the updated MAC.
+            #[must_use]
+            pub fn chain_update(mut self, data: impl AsRef<[u8]>) -> Self {
+                self.update(data);
+                self
+            }
+
+            /// Writes the resulting MAC into an output buffer. Note that the
+            /// length of the output is part of the MAC computation, so
+            /// different output lengths produce unrelated values.
+            pub fn finalize_into(mut self, output: &mut [u8]) {
+                self.finalize_into_reset(output);
+            }
+
+            /// Writes the resulting MAC into an output buffer and resets the
+            /// MAC to its initial state, so that it can be reused.
+            pub fn finalize_into_reset(&mut self, output: &mut [u8]) {
+                right_encode(&mut self.sponge, output.len() as u64 * 8);
+                self.sponge.pad(CSHAKE_DOMAIN);
+                ShakeReader::new(self.sponge.state, $rate).read(output);
+                self.reset();
+            }
+
+            /// Finalizes the MAC in its extendable-output (KMACXOF) mode and
+            /// returns a reader for an arbitrary amount of output.
+            pub fn finalize_xof(mut self) -> ShakeReader {
+                self.finalize_xof_reset()
+            }
+
+            /// Finalizes the MAC in its extendable-output (KMACXOF) mode,
+            /// returning a reader for an arbitrary amount of output, and
+            /// resets the MAC to its initial state so that it can be reused.
+            pub fn finalize_xof_reset(&mut self) -> ShakeReader {
+                right_encode(&mut self.sponge, 0);
+                self.sponge.pad(CSHAKE_DOMAIN);
+                let reader = ShakeReader::new(self.sponge.state, $rate);
+                self.reset();
+                reader
+            }
+
+            /// Resets the MAC to its initial state, discarding any processed
+            /// data but keeping its key and customization string.
+            pub fn reset(&mut self) {
+                self.sponge = self.initial.clone();
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).finish()
+            }
+        }
+    };
+}
+
+/// A KMAC128 keyed message authentication code.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Kmac128;
+/// let key = core::array::from_fn::<u8, 32, _>(|i| 0x40 + i as u8);
+/// let mut mac = [0; 32];
+/// Kmac128::new(&key, b"")
+///     .chain_update([0x00, 0x01, 0x02, 0x03])
+///     .finalize_into(&mut mac);
+/// assert_eq!(mac[..4], [0xe5, 0x78, 0x0b, 0x0d]);
+/// ```
+#[derive(Clone)]
+pub struct Kmac128 {
+    sponge: Sponge<168>,
+    initial: Sponge<168>,
+}
+
+impl_kmac!(Kmac128, 168);
+
+/// A KMAC256 keyed message authentication code.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Kmac256;
+/// let key = core::array::from_fn::<u8, 32, _>(|i| 0x40 + i as u8);
+/// let mut mac = [0; 64];
+/// Kmac256::new(&key, b"My Tagged Application")
+///     .chain_update([0x00, 0x01, 0x02, 0x03])
+///     .finalize_into(&mut mac);
+/// assert_eq!(mac[..4], [0x20, 0xc5, 0x70, 0xc3]);
+/// ```
+#[derive(Clone)]
+pub struct Kmac256 {
+    sponge: Sponge<136>,
+    initial: Sponge<136>,
+}
+
+impl_kmac!(Kmac256, 136);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Shake128, Shake256};
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+    fn read<const N: usize>(mut reader: impl FnMut(&mut [u8])) -> [u8; N] {
+        let mut output = [0; N];
+        reader(&mut output);
+        output
+    }
+
+    #[test]
+    fn cshake_matches_reference_implementation() {
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        let long = [b'x'; 200];
+        for (name, customization) in [
+            (&b""[..], &b"Email Signature"[..]),
+            (b"KMAC", b""),
+            (b"function", b"customization"),
+            (&long[..], &long[..]),
+        ] {
+            for len in [0, 1, 136, 168, 1000] {
+                let data = &data[..len];
+
+                let ours = read::<300>(|out| {
+                    CShake128::new(name, customization)
+                        .chain_update(data)
+                        .finalize_xof()
+                        .read(out)
+                });
+                let expected = read::<300>(|out| {
+                    sha3::CShake128::from_core(sha3::CShake128Core::new_with_function_name(
+                        name,
+                        customization,
+                    ))
+                    .chain(data)
+                    .finalize_xof()
+                    .read(out)
+                });
+                assert_eq!(ours, expected);
+
+                let ours = read::<300>(|out| {
+                    CShake256::new(name, customization)
+                        .chain_update(data)
+                        .finalize_xof()
+                        .read(out)
+                });
+                let expected = read::<300>(|out| {
+                    sha3::CShake256::from_core(sha3::CShake256Core::new_with_function_name(
+                        name,
+                        customization,
+                    ))
+                    .chain(data)
+                    .finalize_xof()
+                    .read(out)
+                });
+                assert_eq!(ours, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn cshake_without_customization_is_shake() {
+        assert_eq!(
+            read::<200>(|out| CShake128::new(b"", b"")
+                .chain_update("a")
+                .finalize_xof()
+                .read(out)),
+            read::<200>(|out| Shake128::new().chain_update("a").finalize_xof().read(out)),
+        );
+        assert_eq!(
+            read::<200>(|out| CShake256::new(b"", b"")
+                .chain_update("a")
+                .finalize_xof()
+                .read(out)),
+            read::<200>(|out| Shake256::new().chain_update("a").finalize_xof().read(out)),
+        );
+    }
+
+    #[test]
+    fn kmac_sample_vectors() {
+        let key = core::array::from_fn::<u8, 32, _>(|i| 0x40 + i as u8);
+        let data = [0x00, 0x01, 0x02, 0x03];
+
+        let mut mac = [0; 32];
+        Kmac128::new(&key, b"")
+            .chain_update(data)
+            .finalize_into(&mut mac);
+        assert_eq!(
+            mac,
+            *crate::Digest::from_hex(
+                "0xe5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e"
+            ),
+        );
+
+        let mut kmac = Kmac128::new(&key, b"My Tagged Application");
+        for _ in 0..2 {
+            kmac.update(data);
+            kmac.finalize_into_reset(&mut mac);
+            assert_eq!(
+                mac,
+                *crate::Digest::from_hex(
+                    "0x3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5"
+                ),
+            );
+        }
+
+        let mut mac = [0; 64];
+        Kmac256::new(&key, b"My Tagged Application")
+            .chain_update(data)
+            .finalize_into(&mut mac);
+        assert_eq!(
+            mac[..32],
+            *crate::Digest::from_hex(
+                "0x20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7"
+            ),
+        );
+        assert_eq!(
+            mac[32..],
+            *crate::Digest::from_hex(
+                "0xf69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd"
+            ),
+        );
+    }
+}
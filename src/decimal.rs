@@ -0,0 +1,225 @@
+//! Module implementing decimal (base-10) parsing and formatting of digests
+//! interpreted as 256-bit big-endian unsigned integers.
+
+use crate::Digest;
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    str,
+};
+
+/// The maximum number of decimal digits of a 256-bit integer.
+const LEN: usize = 78;
+
+/// The largest power of 10 that fits in a `u64`.
+const CHUNK: u128 = 10_000_000_000_000_000_000;
+
+/// The number of decimal digits in a `CHUNK`.
+const CHUNK_DIGITS: usize = 19;
+
+impl Digest {
+    /// Parses a digest from a decimal string, interpreting it as a 256-bit
+    /// big-endian unsigned integer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let mut digest = Digest::default();
+    /// digest[30] = 0x01;
+    /// digest[31] = 0x2c;
+    /// assert_eq!(Digest::from_decimal_str("300").unwrap(), digest);
+    /// ```
+    #[doc(alias = "from_dec_str")]
+    pub fn from_decimal_str(s: &str) -> Result<Self, ParseDecimalError> {
+        if s.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+
+        // Little-endian limbs.
+        let mut limbs = [0_u64; 4];
+        for (index, c) in s.char_indices() {
+            let digit = c
+                .to_digit(10)
+                .ok_or(ParseDecimalError::InvalidDigit { c, index })?;
+            let mut carry = digit as u128;
+            for limb in &mut limbs {
+                let n = (*limb as u128) * 10 + carry;
+                *limb = n as u64;
+                carry = n >> 64;
+            }
+            if carry != 0 {
+                return Err(ParseDecimalError::Overflow);
+            }
+        }
+
+        let mut digest = Self::default();
+        for (chunk, limb) in digest.chunks_exact_mut(8).zip(limbs.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        Ok(digest)
+    }
+
+    /// Formats the digest as a decimal string, interpreting it as a 256-bit
+    /// big-endian unsigned integer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest([0xff; 32]).to_decimal().as_str(),
+    ///     "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+    /// );
+    /// assert_eq!(Digest::default().to_decimal().to_string(), "0");
+    /// ```
+    #[doc(alias = "to_dec_string")]
+    pub fn to_decimal(&self) -> Decimal {
+        // Big-endian limbs.
+        let mut limbs = [0_u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(self.chunks_exact(8)) {
+            *limb = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+
+        let mut buffer = [b'0'; LEN];
+        let mut pos = LEN;
+        loop {
+            let mut rem = 0_u128;
+            for limb in &mut limbs {
+                let n = (rem << 64) | (*limb as u128);
+                *limb = (n / CHUNK) as u64;
+                rem = n % CHUNK;
+            }
+
+            let done = limbs == [0; 4];
+            let mut rem = rem as u64;
+            for _ in 0..CHUNK_DIGITS {
+                pos -= 1;
+                buffer[pos] = b'0' + (rem % 10) as u8;
+                rem /= 10;
+                if done && rem == 0 {
+                    break;
+                }
+            }
+            if done {
+                break;
+            }
+        }
+
+        Decimal { buffer, pos }
+    }
+}
+
+/// A decimal-formatted digest.
+///
+/// This is created with [`Digest::to_decimal`].
+pub struct Decimal {
+    buffer: [u8; LEN],
+    pos: usize,
+}
+
+impl Decimal {
+    /// Returns the decimal string.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Buffer only ever contains ASCII decimal digits.
+        unsafe { str::from_utf8_unchecked(&self.buffer[self.pos..]) }
+    }
+}
+
+impl Debug for Decimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad_integral(true, "", self.as_str())
+    }
+}
+
+/// Represents an error parsing a digest from a decimal string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseDecimalError {
+    /// The decimal string is empty.
+    Empty,
+    /// An invalid character was found.
+    InvalidDigit { c: char, index: usize },
+    /// The decimal value does not fit in 256 bits.
+    Overflow,
+}
+
+impl Display for ParseDecimalError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty decimal string"),
+            Self::InvalidDigit { c, index } => {
+                write!(f, "invalid digit `{c}` at position {index}")
+            }
+            Self::Overflow => write!(f, "decimal value does not fit in 256 bits"),
+        }
+    }
+}
+
+impl core::error::Error for ParseDecimalError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimal_round_trip() {
+        for (digest, s) in [
+            (Digest::default(), "0"),
+            (
+                {
+                    let mut digest = Digest::default();
+                    digest[24..].copy_from_slice(&u64::MAX.to_be_bytes());
+                    digest
+                },
+                "18446744073709551615",
+            ),
+            (
+                {
+                    let mut digest = Digest::default();
+                    digest[23] = 0x01;
+                    digest
+                },
+                "18446744073709551616",
+            ),
+            (
+                Digest([0xee; 32]),
+                "108072616621495115728666252674775380663051985687931193103493745074052254330606",
+            ),
+        ] {
+            assert_eq!(digest.to_decimal().as_str(), s);
+            assert_eq!(Digest::from_decimal_str(s).unwrap(), digest);
+        }
+    }
+
+    #[test]
+    fn decimal_leading_zeros() {
+        assert_eq!(
+            Digest::from_decimal_str(&format!("{}42", "0".repeat(100))).unwrap(),
+            Digest::from_decimal_str("42").unwrap(),
+        );
+    }
+
+    #[test]
+    fn decimal_errors() {
+        assert_eq!(Digest::from_decimal_str(""), Err(ParseDecimalError::Empty));
+        assert_eq!(
+            Digest::from_decimal_str("12a"),
+            Err(ParseDecimalError::InvalidDigit { c: 'a', index: 2 }),
+        );
+        assert_eq!(
+            Digest::from_decimal_str(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639936"
+            ),
+            Err(ParseDecimalError::Overflow),
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! Module implementing an incremental hex decoder for Ethereum 32-byte digests.
+
+use crate::{hex, Digest, ParseDigestError};
+
+/// An incremental hex decoder for digests.
+///
+/// The decoder can be fed hex characters in arbitrarily sized chunks, and
+/// yields a digest once 64 hex digits have been consumed. Each digest may
+/// optionally be prefixed with `0x`. ASCII whitespace, commas and semicolons
+/// between digests are skipped, so that consecutive digests can be decoded
+/// from line or comma separated streams.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, HexDecoder};
+/// let mut decoder = HexDecoder::new();
+/// let mut digests = Vec::new();
+/// for chunk in [
+///     "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+///     "eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee\n0x",
+///     "eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee\n",
+/// ] {
+///     let mut chunk = chunk.as_bytes();
+///     while !chunk.is_empty() {
+///         let (consumed, digest) = decoder.decode(chunk).unwrap();
+///         digests.extend(digest);
+///         chunk = &chunk[consumed..];
+///     }
+/// }
+/// decoder.finish().unwrap();
+///
+/// assert_eq!(digests, [Digest([0xee; 32]); 2]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct HexDecoder {
+    digest: Digest,
+    index: usize,
+    nibbles: usize,
+}
+
+impl HexDecoder {
+    /// Creates a new decoder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds hex characters to the decoder.
+    ///
+    /// Returns the number of bytes consumed from the input and, if one was
+    /// completed, the decoded digest. Decoding stops after each digest so that
+    /// the remaining input can be fed back into the decoder. Separators are
+    /// only skipped before the start of a digest, and are an error within one.
+    ///
+    /// On error, the decoder is reset and the error index is relative to the
+    /// start of the digest being decoded.
+    pub fn decode(&mut self, input: &[u8]) -> Result<(usize, Option<Digest>), ParseDigestError> {
+        for (i, &c) in input.iter().enumerate() {
+            if self.index == 0 && is_separator(c) {
+                continue;
+            }
+
+            let index = self.index;
+            self.index += 1;
+
+            // A leading `0` followed by an `x` is a prefix and not a digit.
+            if index == 1 && self.nibbles == 1 && self.digest[0] == 0 && c == b'x' {
+                self.nibbles = 0;
+                continue;
+            }
+
+            let nibble = match hex::nibble(c) {
+                Some(nibble) => nibble,
+                None => {
+                    *self = Self::default();
+                    return Err(ParseDigestError::InvalidHexCharacter {
+                        c: if c.is_ascii() {
+                            c as char
+                        } else {
+                            char::REPLACEMENT_CHARACTER
+                        },
+                        index,
+                    });
+                }
+            };
+            self.digest[self.nibbles / 2] |= nibble << (4 * (1 - self.nibbles % 2));
+            self.nibbles += 1;
+
+            if self.nibbles == 64 {
+                let digest = self.digest;
+                *self = Self::default();
+                return Ok((i + 1, Some(digest)));
+            }
+        }
+
+        Ok((input.len(), None))
+    }
+
+    /// Finishes decoding, returning an error if a partially decoded digest is
+    /// pending.
+    pub fn finish(self) -> Result<(), ParseDigestError> {
+        if self.index == 0 {
+            Ok(())
+        } else {
+            Err(ParseDigestError::InvalidLength {
+                len: self.index,
+                expected: self.index + 64 - self.nibbles,
+            })
+        }
+    }
+}
+
+/// Returns whether a character separates consecutive digests.
+fn is_separator(c: u8) -> bool {
+    c.is_ascii_whitespace() || c == b',' || c == b';'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_byte_by_byte() {
+        let input = "0x00eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
+        let mut expected = Digest([0xee; 32]);
+        expected[0] = 0;
+
+        let mut decoder = HexDecoder::new();
+        let mut digests = Vec::new();
+        for c in input.bytes() {
+            let (consumed, digest) = decoder.decode(&[c]).unwrap();
+            assert_eq!(consumed, 1);
+            digests.extend(digest);
+        }
+
+        assert_eq!(digests, [expected]);
+        decoder.finish().unwrap();
+    }
+
+    #[test]
+    fn decode_without_prefix() {
+        let mut decoder = HexDecoder::new();
+        let input = [b'0'; 64];
+        assert_eq!(
+            decoder.decode(&input).unwrap(),
+            (64, Some(Digest::default())),
+        );
+    }
+
+    #[test]
+    fn decode_separated_digests() {
+        let input = format!("{}\n{}\r\n, ", Digest([0xee; 32]), Digest([0x42; 32]));
+        for chunk_size in [1, 7, 64, 65, 66, 67, input.len()] {
+            let mut decoder = HexDecoder::new();
+            let mut digests = Vec::new();
+            for mut chunk in input.as_bytes().chunks(chunk_size) {
+                while !chunk.is_empty() {
+                    let (consumed, digest) = decoder.decode(chunk).unwrap();
+                    digests.extend(digest);
+                    chunk = &chunk[consumed..];
+                }
+            }
+            decoder.finish().unwrap();
+            assert_eq!(digests, [Digest([0xee; 32]), Digest([0x42; 32])]);
+        }
+    }
+
+    #[test]
+    fn decode_errors() {
+        let mut decoder = HexDecoder::new();
+        decoder.decode(b"0xeeee").unwrap();
+        assert_eq!(
+            decoder.decode(b"eeg"),
+            Err(ParseDigestError::InvalidHexCharacter { c: 'g', index: 8 }),
+        );
+        assert_eq!(
+            decoder.decode(b"0x\n"),
+            Err(ParseDigestError::InvalidHexCharacter { c: '\n', index: 2 }),
+        );
+
+        decoder.decode(b"eeee").unwrap();
+        assert_eq!(
+            decoder.finish(),
+            Err(ParseDigestError::InvalidLength {
+                len: 4,
+                expected: 64,
+            }),
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Module implementing RustCrypto `digest` traits for the Keccak-256 hasher.
+
+use crate::Keccak;
+use ::digest::{
+    consts::{U136, U32},
+    core_api::BlockSizeUser,
+    FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+impl HashMarker for Keccak {}
+
+impl BlockSizeUser for Keccak {
+    type BlockSize = U136;
+}
+
+impl OutputSizeUser for Keccak {
+    type OutputSize = U32;
+}
+
+impl Update for Keccak {
+    fn update(&mut self, data: &[u8]) {
+        Keccak::update(self, data);
+    }
+}
+
+impl FixedOutput for Keccak {
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        FixedOutputReset::finalize_into_reset(&mut self, out);
+    }
+}
+
+impl FixedOutputReset for Keccak {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        Keccak::finalize_into_reset(self, out.as_mut());
+    }
+}
+
+impl Reset for Keccak {
+    fn reset(&mut self) {
+        Keccak::reset(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Digest, Keccak};
+
+    fn hash<D: ::digest::Digest>(data: &[u8]) -> [u8; 32] {
+        D::digest(data).as_slice().try_into().unwrap()
+    }
+
+    #[test]
+    fn generic_digest() {
+        for data in [&b""[..], b"Hello Ethereum!", &[0xee; 1000]] {
+            assert_eq!(hash::<Keccak>(data), Digest::of(data).0);
+            assert_eq!(hash::<Keccak>(data), hash::<sha3::Keccak256>(data));
+        }
+    }
+}
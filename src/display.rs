@@ -0,0 +1,102 @@
+//! Module implementing display adapters for Ethereum 32-byte digests.
+
+use crate::{
+    buffer::{self, Alphabet},
+    Digest,
+};
+use core::{
+    fmt::{self, Debug, Display, Formatter, Write as _},
+    str,
+};
+
+/// A display adapter for formatting a digest with a separator every few bytes.
+///
+/// This is created with [`Digest::grouped`].
+#[derive(Clone, Copy)]
+pub struct Grouped<'a> {
+    pub(crate) digest: &'a Digest,
+    pub(crate) size: usize,
+}
+
+impl Debug for Grouped<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Grouped<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let buffer = buffer::fmt(self.digest, Alphabet::default());
+        let hex = buffer.as_bytes_str().as_bytes();
+
+        f.write_str("0x")?;
+        for (i, group) in hex.chunks(self.size * 2).enumerate() {
+            if i > 0 {
+                f.write_str("_")?;
+            }
+            // SAFETY: The formatting buffer only contains ASCII characters, so
+            // it can be split at any byte offset.
+            f.write_str(unsafe { str::from_utf8_unchecked(group) })?;
+        }
+        Ok(())
+    }
+}
+
+/// A display adapter for formatting an abbreviated digest.
+///
+/// This is created with [`Digest::short`].
+#[derive(Clone, Copy)]
+pub struct Short<'a> {
+    pub(crate) digest: &'a Digest,
+}
+
+impl Debug for Short<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Short<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        truncated(self.digest, f.precision().unwrap_or(8), f)
+    }
+}
+
+/// Formats a digest keeping only `digits` hex digits, split between the start
+/// and the end of the digest with an ellipsis in between.
+pub(crate) fn truncated(digest: &Digest, digits: usize, f: &mut Formatter) -> fmt::Result {
+    let buffer = buffer::fmt(digest, Alphabet::default());
+    let hex = buffer.as_bytes_str();
+    if digits >= hex.len() {
+        return pad(f, &[buffer.as_str()]);
+    }
+
+    let head = digits.div_ceil(2);
+    let tail = digits / 2;
+    pad(f, &["0x", &hex[..head], "…", &hex[hex.len() - tail..]])
+}
+
+/// Writes string parts to a formatter, honouring its width, fill and
+/// alignment. Unlike [`Formatter::pad`], the precision is ignored since it is
+/// used for specifying the number of digits to display instead.
+fn pad(f: &mut Formatter, parts: &[&str]) -> fmt::Result {
+    let len = parts.iter().map(|part| part.chars().count()).sum::<usize>();
+    let padding = f.width().unwrap_or_default().saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    for part in parts {
+        f.write_str(part)?;
+    }
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
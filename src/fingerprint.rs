@@ -0,0 +1,158 @@
+//! Module implementing visual fingerprints for Ethereum 32-byte digests.
+//!
+//! Fingerprints are rendered with the "drunken bishop" algorithm used by
+//! OpenSSH for host key randomart, so that they can easily be reproduced by
+//! other implementations:
+//!
+//! 1. A bishop starts in the center of a 17x9 board.
+//! 2. Each byte of the digest is consumed in order, and each byte is split into
+//!    four 2-bit moves starting from the least significant bits. For each move,
+//!    the low bit moves the bishop right (1) or left (0) and the high bit moves
+//!    it down (1) or up (0). Moves into a wall slide along it instead.
+//! 3. Every time the bishop lands on a square, that square's visit count is
+//!    incremented (saturating at 14).
+//! 4. Squares are rendered as ` .o+=*BOX@%&#/^` based on their visit count,
+//!    with the start and end positions marked with `S` and `E`.
+
+use crate::Digest;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// The width of the board.
+const WIDTH: usize = 17;
+
+/// The height of the board.
+const HEIGHT: usize = 9;
+
+/// The symbols used for rendering the board.
+const SYMBOLS: &[u8; 17] = b" .o+=*BOX@%&#/^SE";
+
+/// The symbol index of the start position.
+const START: u8 = 15;
+
+/// The symbol index of the end position.
+const END: u8 = 16;
+
+impl Digest {
+    /// Renders a "drunken bishop" visual fingerprint of the digest, in the
+    /// style of OpenSSH randomart. Fingerprints are meant for humans to
+    /// quickly compare digests.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest([0xee; 32]);
+    /// assert_eq!(
+    ///     digest.fingerprint().to_string(),
+    ///     "+-----------------+\n\
+    ///      |                 |\n\
+    ///      |                 |\n\
+    ///      |                 |\n\
+    ///      |                 |\n\
+    ///      |        S        |\n\
+    ///      |       .         |\n\
+    ///      |        .        |\n\
+    ///      |       .         |\n\
+    ///      |       ^E        |\n\
+    ///      +-----------------+",
+    /// );
+    /// ```
+    pub fn fingerprint(&self) -> Fingerprint {
+        let mut board = [[0_u8; WIDTH]; HEIGHT];
+        let (mut x, mut y) = (WIDTH / 2, HEIGHT / 2);
+
+        for mut byte in self.0 {
+            for _ in 0..4 {
+                x = if byte & 1 != 0 {
+                    (x + 1).min(WIDTH - 1)
+                } else {
+                    x.saturating_sub(1)
+                };
+                y = if byte & 2 != 0 {
+                    (y + 1).min(HEIGHT - 1)
+                } else {
+                    y.saturating_sub(1)
+                };
+                if board[y][x] < START - 1 {
+                    board[y][x] += 1;
+                }
+                byte >>= 2;
+            }
+        }
+
+        board[HEIGHT / 2][WIDTH / 2] = START;
+        board[y][x] = END;
+
+        Fingerprint(board)
+    }
+}
+
+/// A "drunken bishop" visual fingerprint of a digest.
+///
+/// This is created with [`Digest::fingerprint`].
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct Fingerprint([[u8; WIDTH]; HEIGHT]);
+
+impl Fingerprint {
+    /// Returns the rendered rows of the fingerprint, without the border.
+    pub fn rows(&self) -> impl Iterator<Item = [char; WIDTH]> + '_ {
+        self.0
+            .iter()
+            .map(|row| row.map(|square| SYMBOLS[square as usize] as char))
+    }
+}
+
+impl Debug for Fingerprint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Fingerprint(\n")?;
+        Display::fmt(self, f)?;
+        f.write_str("\n)")
+    }
+}
+
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        const BORDER: &str = "+-----------------+";
+
+        f.write_str(BORDER)?;
+        for row in self.rows() {
+            f.write_str("\n|")?;
+            for square in row {
+                fmt::Write::write_char(f, square)?;
+            }
+            f.write_str("|")?;
+        }
+        f.write_str("\n")?;
+        f.write_str(BORDER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_rendering() {
+        let mut digest = Digest::default();
+        for (i, byte) in digest.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        assert_eq!(
+            digest.fingerprint().to_string(),
+            "+-----------------+\n\
+             |^^O@@E.          |\n\
+             |@@O++..          |\n\
+             |o+.. ..          |\n\
+             |       .         |\n\
+             |        S        |\n\
+             |                 |\n\
+             |                 |\n\
+             |                 |\n\
+             |                 |\n\
+             +-----------------+",
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! Module implementing random digest generation from the operating system's
+//! random number generator with the `getrandom` crate.
+
+use crate::Digest;
+
+impl Digest {
+    /// Generates a random digest from the operating system's (or hardware)
+    /// random number generator. Unlike `Digest::random`, this does not
+    /// depend on the `rand` crate and works in `no_std` environments, making
+    /// it suitable for generating salts and nonces.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let salt = Digest::random_os().unwrap();
+    /// assert_ne!(salt, Digest::random_os().unwrap());
+    /// ```
+    pub fn random_os() -> Result<Self, getrandom::Error> {
+        let mut digest = Self::ZERO;
+        getrandom::getrandom(&mut digest.0)?;
+        Ok(digest)
+    }
+}
@@ -0,0 +1,253 @@
+//! Module implementing hashing of structured values with a canonical encoding.
+
+use crate::{Digest, Keccak};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+/// A value that can be hashed with a well-defined, canonical encoding.
+///
+/// Unlike [`Hash`](core::hash::Hash), the encoding is fully specified, so it
+/// is stable across platforms, Rust versions and versions of this crate:
+/// - Integers are encoded as big-endian bytes of their width; `usize` and
+///   `isize` are always encoded as 64-bit integers.
+/// - `bool` is encoded as a single `0x00` or `0x01` byte.
+/// - Digests and fixed-size arrays are encoded as the concatenation of their
+///   elements, without a length prefix.
+/// - Slices, strings and vectors are encoded as their length as a 64-bit
+///   big-endian integer followed by the concatenation of their elements
+///   (string contents are encoded as UTF-8 bytes).
+/// - `Option` values are encoded as a single `0x00` byte for `None`, or a
+///   `0x01` byte followed by the value for `Some`.
+/// - Tuples are encoded as the concatenation of their elements.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, Hashable};
+/// let value = (42_u16, "abc", Some(true));
+/// assert_eq!(
+///     Digest::of_value(&value),
+///     Digest::of([
+///         0x00, 0x2a, // 42_u16
+///         0, 0, 0, 0, 0, 0, 0, 3, b'a', b'b', b'c', // "abc"
+///         0x01, 0x01, // Some(true)
+///     ]),
+/// );
+/// ```
+pub trait Hashable {
+    /// Processes the encoded value with a hasher.
+    fn hash_into(&self, hasher: &mut Keccak);
+
+    /// Processes the encoded elements of a slice with a hasher, without a
+    /// length prefix. This can be overridden to process elements in bulk.
+    fn hash_slice_into(data: &[Self], hasher: &mut Keccak)
+    where
+        Self: Sized,
+    {
+        for item in data {
+            item.hash_into(hasher);
+        }
+    }
+}
+
+impl Digest {
+    /// Creates a digest by hashing a value with its canonical [`Hashable`]
+    /// encoding.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::of_value(&0x1234_u16), Digest::of([0x12, 0x34]));
+    /// assert_ne!(
+    ///     Digest::of_value(&("ab", "c")),
+    ///     Digest::of_value(&("a", "bc")),
+    /// );
+    /// ```
+    pub fn of_value(value: &(impl Hashable + ?Sized)) -> Self {
+        let mut hasher = Keccak::new();
+        value.hash_into(&mut hasher);
+        hasher.finalize()
+    }
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {$(
+        impl Hashable for $t {
+            fn hash_into(&self, hasher: &mut Keccak) {
+                hasher.update(self.to_be_bytes());
+            }
+        }
+    )*};
+}
+
+impl_int!(u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Hashable for u8 {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        hasher.update([*self]);
+    }
+
+    fn hash_slice_into(data: &[Self], hasher: &mut Keccak) {
+        hasher.update(data);
+    }
+}
+
+impl Hashable for usize {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        (*self as u64).hash_into(hasher);
+    }
+}
+
+impl Hashable for isize {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        (*self as i64).hash_into(hasher);
+    }
+}
+
+impl Hashable for bool {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        u8::from(*self).hash_into(hasher);
+    }
+}
+
+impl Hashable for Digest {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        hasher.update(self);
+    }
+}
+
+impl<T: Hashable, const N: usize> Hashable for [T; N] {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        T::hash_slice_into(self, hasher);
+    }
+}
+
+impl<T: Hashable> Hashable for [T] {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        self.len().hash_into(hasher);
+        T::hash_slice_into(self, hasher);
+    }
+}
+
+impl Hashable for str {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        self.as_bytes().hash_into(hasher);
+    }
+}
+
+impl<T: Hashable> Hashable for Option<T> {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        match self {
+            None => false.hash_into(hasher),
+            Some(value) => {
+                true.hash_into(hasher);
+                value.hash_into(hasher);
+            }
+        }
+    }
+}
+
+impl<T: Hashable + ?Sized> Hashable for &T {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        (**self).hash_into(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Hashable + ?Sized> Hashable for Box<T> {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        (**self).hash_into(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Hashable> Hashable for Vec<T> {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        self.as_slice().hash_into(hasher);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Hashable for String {
+    fn hash_into(&self, hasher: &mut Keccak) {
+        self.as_str().hash_into(hasher);
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident)*) => {
+        impl<$($name: Hashable),*> Hashable for ($($name,)*) {
+            #[allow(non_snake_case, unused_variables)]
+            fn hash_into(&self, hasher: &mut Keccak) {
+                let ($($name,)*) = self;
+                $($name.hash_into(hasher);)*
+            }
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(A);
+impl_tuple!(A B);
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+impl_tuple!(A B C D E);
+impl_tuple!(A B C D E F);
+impl_tuple!(A B C D E F G);
+impl_tuple!(A B C D E F G H);
+impl_tuple!(A B C D E F G H I);
+impl_tuple!(A B C D E F G H I J);
+impl_tuple!(A B C D E F G H I J K);
+impl_tuple!(A B C D E F G H I J K L);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_encoding() {
+        assert_eq!(Digest::of_value(&-2_i8), Digest::of([0xfe]));
+        assert_eq!(Digest::of_value(&1_usize), Digest::of(1_u64.to_be_bytes()));
+        assert_eq!(Digest::of_value(&[1_u8, 2, 3]), Digest::of([1, 2, 3]));
+        assert_eq!(
+            Digest::of_value(&[0x0102_u16, 0x0304]),
+            Digest::of([1, 2, 3, 4]),
+        );
+        assert_eq!(
+            Digest::of_value(&b"abc"[..]),
+            Digest::of(b"\0\0\0\0\0\0\0\x03abc"),
+        );
+        assert_eq!(Digest::of_value(&None::<u64>), Digest::of([0]));
+        assert_eq!(Digest::of_value(&()), Digest::of([]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn owned_values_match_borrowed() {
+        assert_eq!(
+            Digest::of_value("abc"),
+            Digest::of_value(&String::from("abc")),
+        );
+        assert_eq!(
+            Digest::of_value(&vec![Digest::ZERO]),
+            Digest::of_value(&[Digest::ZERO][..]),
+        );
+    }
+
+    #[test]
+    fn unambiguous_concatenation() {
+        assert_ne!(
+            Digest::of_value(&(&[1_u8][..], &[2_u8, 3][..])),
+            Digest::of_value(&(&[1_u8, 2][..], &[3_u8][..])),
+        );
+        assert_ne!(
+            Digest::of_value(&(None::<u8>, Some(0_u8))),
+            Digest::of_value(&(Some(0_u8), None::<u8>)),
+        );
+    }
+}
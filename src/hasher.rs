@@ -0,0 +1,97 @@
+//! Module implementing a hasher for hash maps and sets keyed by digests.
+
+#[cfg(feature = "std")]
+use crate::Digest;
+use core::hash::{BuildHasherDefault, Hasher};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// A [`HashMap`] keyed by digests, using [`DigestHasher`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, DigestMap};
+/// let mut map = DigestMap::default();
+/// map.insert(Digest([0xee; 32]), "hello");
+/// assert_eq!(map[&Digest([0xee; 32])], "hello");
+/// ```
+#[cfg(feature = "std")]
+pub type DigestMap<V> = HashMap<Digest, V, BuildDigestHasher>;
+
+/// A [`HashSet`] of digests, using [`DigestHasher`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, DigestSet};
+/// let set = [Digest([0xee; 32]), Digest([0xee; 32])]
+///     .into_iter()
+///     .collect::<DigestSet>();
+/// assert_eq!(set.len(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub type DigestSet = HashSet<Digest, BuildDigestHasher>;
+
+/// A [`BuildHasher`](core::hash::BuildHasher) for [`DigestHasher`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{BuildDigestHasher, Digest};
+/// # use std::collections::HashMap;
+/// let mut map = HashMap::with_capacity_and_hasher(16, BuildDigestHasher::default());
+/// map.insert(Digest([0xee; 32]), 42);
+/// assert_eq!(map.get(&Digest([0xee; 32])), Some(&42));
+/// ```
+pub type BuildDigestHasher = BuildHasherDefault<DigestHasher>;
+
+/// A hasher for digest keys.
+///
+/// Digests are already uniformly distributed, so re-hashing them with a
+/// general purpose hasher such as SipHash is wasted work. Instead, this hasher
+/// uses the first 8 bytes of each write as the hash directly.
+///
+/// Note that this hasher is only suitable for keys that are digests (or
+/// wrappers around them). Other key types can be hashed, but will likely
+/// produce poorly distributed hashes. Additionally, it provides no protection
+/// against maliciously chosen keys that are not the output of a hash function.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DigestHasher(u64);
+
+impl Hasher for DigestHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut word = [0; 8];
+        let len = bytes.len().min(8);
+        word[..len].copy_from_slice(&bytes[..len]);
+        self.0 = self.0.rotate_left(32) ^ u64::from_ne_bytes(word);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use core::hash::BuildHasher as _;
+
+    #[test]
+    fn hashes_digest_prefix() {
+        let build = BuildDigestHasher::default();
+        let a = Digest::ZERO.with_byte(0, 1);
+        let b = Digest::ZERO.with_byte(31, 1);
+
+        assert_eq!(build.hash_one(a), build.hash_one(a));
+        assert_ne!(build.hash_one(a), build.hash_one(Digest::ZERO));
+        // Only the first 8 bytes of the digest contribute to the hash.
+        assert_eq!(build.hash_one(b), build.hash_one(Digest::ZERO));
+    }
+}
@@ -1,61 +1,329 @@
 //! Internal module used for hex-string parsing.
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+use crate::simd;
 use core::{
     fmt::{self, Display, Formatter},
-    mem::{self, MaybeUninit},
+    ops::Range,
 };
 
 /// Decode a hex string into digest bytes.
 pub fn decode(s: &str) -> Result<[u8; 32], ParseDigestError> {
-    let (s, ch_offset) = match s.strip_prefix("0x") {
+    decode_bytes(s.as_bytes()).map_err(|err| match err {
+        // All bytes preceding the invalid character are valid ASCII hex
+        // digits, so the index is always on a character boundary.
+        ParseDigestError::InvalidHexCharacter { index, .. } => {
+            ParseDigestError::InvalidHexCharacter {
+                c: s[index..].chars().next().unwrap(),
+                index,
+            }
+        }
+        err => err,
+    })
+}
+
+/// Decode ASCII hex bytes into digest bytes.
+///
+/// Since the input is not necessarily valid UTF-8, invalid non-ASCII bytes are
+/// reported as the `U+FFFD` replacement character.
+pub fn decode_bytes(s: &[u8]) -> Result<[u8; 32], ParseDigestError> {
+    let (s, ch_offset) = match s.strip_prefix(b"0x") {
         Some(s) => (s, 2),
         None => (s, 0),
     };
-    if s.len() != 64 {
-        return Err(ParseDigestError::InvalidLength);
-    }
 
-    let mut bytes = [MaybeUninit::<u8>::uninit(); 32];
-    let nibble = |c| match c {
-        b'0'..=b'9' => Some(c - b'0'),
-        b'A'..=b'F' => Some(c - b'A' + 0xa),
-        b'a'..=b'f' => Some(c - b'a' + 0xa),
-        _ => None,
-    };
     let invalid_char = |i: usize| ParseDigestError::InvalidHexCharacter {
-        c: s[i..].chars().next().unwrap(),
+        c: if s[i].is_ascii() {
+            s[i] as char
+        } else {
+            char::REPLACEMENT_CHARACTER
+        },
         index: i + ch_offset,
     };
 
-    for (i, ch) in s.as_bytes().chunks(2).enumerate() {
-        let hi = nibble(ch[0]).ok_or_else(|| invalid_char(i * 2))?;
-        let lo = nibble(ch[1]).ok_or_else(|| invalid_char(i * 2 + 1))?;
-        bytes[i].write((hi << 4) + lo);
+    if s.len() != 64 {
+        // Invalid characters such as stray whitespace or upper-case `0X`
+        // prefixes are common causes for invalid lengths, so point at the
+        // first one instead of just reporting the length.
+        if let Some(i) = s.iter().position(|&c| nibble(c).is_none()) {
+            return Err(invalid_char(i));
+        }
+        return Err(ParseDigestError::InvalidLength {
+            len: s.len() + ch_offset,
+            expected: 64 + ch_offset,
+        });
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    if simd::available() {
+        // SAFETY: SIMD support was checked. Invalid characters fall through to
+        // the scalar implementation for error reporting.
+        if let Some(bytes) = unsafe { simd::decode(s.try_into().unwrap()) } {
+            return Ok(bytes);
+        }
+    }
+
+    if let Some(bytes) = decode_scalar(s.try_into().unwrap()) {
+        return Ok(bytes);
+    }
+
+    // Slow path for reporting the position of the invalid character. Decoding
+    // only fails if there is at least one.
+    let i = s.iter().position(|&c| nibble(c).is_none()).unwrap();
+    Err(invalid_char(i))
+}
+
+/// Decode 64 hex characters into 32 bytes. Returns `None` if any of the
+/// characters are not valid hex digits.
+///
+/// This is branchless, processing 8 bytes at a time and only checking for
+/// invalid characters at the end.
+fn decode_scalar(s: &[u8; 64]) -> Option<[u8; 32]> {
+    let mut bytes = [0_u8; 32];
+    let mut invalid = 0;
+    for (out, hex) in bytes.chunks_exact_mut(8).zip(s.chunks_exact(16)) {
+        for (byte, ch) in out.iter_mut().zip(hex.chunks_exact(2)) {
+            let hi = NIBBLES[ch[0] as usize];
+            let lo = NIBBLES[ch[1] as usize];
+            invalid |= hi | lo;
+            *byte = (hi << 4) | lo;
+        }
+    }
+
+    // Valid nibbles never have their high bits set.
+    if invalid & 0xf0 != 0 {
+        return None;
+    }
+    Some(bytes)
+}
+
+/// Returns whether or not the bytes are a valid hex digest string with an
+/// optional `0x` prefix.
+pub fn is_valid(s: &[u8]) -> bool {
+    let s = s.strip_prefix(b"0x").unwrap_or(s);
+    s.len() == 64 && s.iter().all(u8::is_ascii_hexdigit)
+}
+
+/// Decode a hex string into digest bytes with the specified prefix policy.
+pub fn decode_with(s: &str, prefix: PrefixPolicy) -> Result<[u8; 32], ParseDigestError> {
+    match (prefix, s.starts_with("0x")) {
+        (PrefixPolicy::Required, false) | (PrefixPolicy::Forbidden, true) => {
+            Err(ParseDigestError::InvalidPrefix { policy: prefix })
+        }
+        _ => decode(s),
+    }
+}
+
+/// Leniently decode a hex string into digest bytes.
+///
+/// This allows surrounding whitespace, an optional `0x` or `0X` prefix, and `_`
+/// separators between hex digits.
+pub fn decode_lenient(input: &str) -> Result<[u8; 32], ParseDigestError> {
+    let trimmed = input.trim_start();
+    let offset = input.len() - trimmed.len();
+    let trimmed = trimmed.trim_end();
+    let (s, ch_offset) = match trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+    {
+        Some(s) => (s, offset + 2),
+        None => (trimmed, offset),
+    };
+
+    let mut bytes = [0; 32];
+    let mut len = 0;
+    for (i, c) in s.char_indices() {
+        if c == '_' {
+            continue;
+        }
+        let n =
+            u8::try_from(c)
+                .ok()
+                .and_then(nibble)
+                .ok_or(ParseDigestError::InvalidHexCharacter {
+                    c,
+                    index: i + ch_offset,
+                })?;
+        // Keep counting digits past the end of the digest in order to report
+        // the expected length.
+        if let Some(byte) = bytes.get_mut(len / 2) {
+            *byte |= n << (4 * (1 - len % 2));
+        }
+        len += 1;
+    }
+    if len != 64 {
+        return Err(ParseDigestError::InvalidLength {
+            len: input.len(),
+            expected: input.len() + 64 - len,
+        });
     }
 
-    let bytes = unsafe { mem::transmute(bytes) };
     Ok(bytes)
 }
 
+/// Decode a hex string with an optional `0x` prefix into digest bytes in a
+/// `const` context, panicking on invalid input.
+pub const fn decode_const(s: &str) -> [u8; 32] {
+    let s = s.as_bytes();
+    let offset = if s.len() >= 2 && s[0] == b'0' && s[1] == b'x' {
+        2
+    } else {
+        0
+    };
+    assert!(s.len() == offset + 64, "invalid hex digest length");
+
+    let mut bytes = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        let hi = NIBBLES[s[offset + i * 2] as usize];
+        let lo = NIBBLES[s[offset + i * 2 + 1] as usize];
+        assert!((hi | lo) & 0xf0 == 0, "invalid hex digit");
+        bytes[i] = (hi << 4) | lo;
+        i += 1;
+    }
+    bytes
+}
+
+/// Lookup-table mapping characters to their hex digit values, with invalid
+/// characters mapping to `0xff`.
+const NIBBLES: [u8; 256] = {
+    let mut lut = [0xff; 256];
+    let mut i = 0;
+    while i < 16 {
+        lut[b"0123456789abcdef"[i] as usize] = i as u8;
+        lut[b"0123456789ABCDEF"[i] as usize] = i as u8;
+        i += 1;
+    }
+    lut
+};
+
+/// Returns the value of a hex digit.
+pub fn nibble(c: u8) -> Option<u8> {
+    match NIBBLES[c as usize] {
+        0xff => None,
+        n => Some(n),
+    }
+}
+
+/// The policy for the `0x` prefix when parsing digests from hex strings.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum PrefixPolicy {
+    /// The `0x` prefix is required, as is the case for JSON-RPC.
+    Required,
+    /// The `0x` prefix is optional.
+    #[default]
+    Optional,
+    /// The `0x` prefix is not allowed.
+    Forbidden,
+}
+
 /// Represents an error parsing an digest from a string.
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseDigestError {
     /// The hex string does not have the correct length.
-    InvalidLength,
+    InvalidLength {
+        /// The length of the input in bytes.
+        len: usize,
+        /// The length in bytes the input was expected to have.
+        expected: usize,
+    },
     /// An invalid character was found.
     InvalidHexCharacter { c: char, index: usize },
+    /// The `0x` prefix is missing or not allowed, according to the prefix
+    /// policy that was used for parsing.
+    InvalidPrefix { policy: PrefixPolicy },
+}
+
+impl ParseDigestError {
+    /// Returns the byte range of the input that caused the error. For invalid
+    /// lengths, this is the entire input.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            Self::InvalidLength { len, .. } => 0..*len,
+            Self::InvalidHexCharacter { c, index } => *index..*index + c.len_utf8(),
+            Self::InvalidPrefix { policy } => match policy {
+                PrefixPolicy::Forbidden => 0..2,
+                _ => 0..0,
+            },
+        }
+    }
+
+    /// Returns a suggestion for fixing the input, if the error looks like it
+    /// was caused by a common mistake.
+    pub fn suggestion(&self) -> Option<Suggestion> {
+        match *self {
+            Self::InvalidLength { len, expected } if len + 1 == expected => {
+                Some(Suggestion::MissingDigit)
+            }
+            Self::InvalidLength { len, expected } if len == expected + 1 => {
+                Some(Suggestion::ExtraDigit)
+            }
+            Self::InvalidHexCharacter { c, .. } if c.is_whitespace() => {
+                Some(Suggestion::RemoveWhitespace)
+            }
+            Self::InvalidHexCharacter { c, index } => {
+                let replacement = match c {
+                    'X' if index == 1 => 'x',
+                    'O' | 'o' => '0',
+                    'I' | 'i' | 'l' => '1',
+                    _ => return None,
+                };
+                Some(Suggestion::ReplaceCharacter {
+                    found: c,
+                    replacement,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A suggestion for fixing a digest hex string that failed to parse.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Suggestion {
+    /// The hex string is missing a single digit.
+    MissingDigit,
+    /// The hex string has a single extra digit.
+    ExtraDigit,
+    /// The hex string contains a character that looks like a hex digit.
+    ReplaceCharacter { found: char, replacement: char },
+    /// The hex string contains stray whitespace.
+    RemoveWhitespace,
+}
+
+impl Display for Suggestion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingDigit => write!(f, "a hex digit is missing"),
+            Self::ExtraDigit => write!(f, "there is an extra hex digit"),
+            Self::ReplaceCharacter { found, replacement } => {
+                write!(f, "replace `{found}` with `{replacement}`")
+            }
+            Self::RemoveWhitespace => write!(f, "remove whitespace"),
+        }
+    }
 }
 
 impl Display for ParseDigestError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
-            Self::InvalidLength { .. } => write!(f, "invalid hex string length"),
+            Self::InvalidLength { len, expected } => {
+                write!(f, "invalid hex string length {len}, expected {expected}")
+            }
             Self::InvalidHexCharacter { c, index } => {
                 write!(f, "invalid character `{c}` at position {index}")
             }
+            Self::InvalidPrefix { policy } => match policy {
+                PrefixPolicy::Forbidden => write!(f, "unexpected `0x` prefix"),
+                _ => write!(f, "missing `0x` prefix"),
+            },
+        }?;
+        if let Some(suggestion) = self.suggestion() {
+            write!(f, " ({suggestion})")?;
         }
+        Ok(())
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ParseDigestError {}
+impl core::error::Error for ParseDigestError {}
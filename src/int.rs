@@ -0,0 +1,307 @@
+//! Module implementing conversions and arithmetic for digests interpreted as
+//! 256-bit big-endian unsigned integers.
+
+use crate::Digest;
+
+impl Digest {
+    /// Creates a digest from 64-bit words, most significant word first.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::from_be_words([0, 0, 0, 0x0102]),
+    ///     Digest::ZERO.with_byte(30, 0x01).with_byte(31, 0x02),
+    /// );
+    /// ```
+    pub fn from_be_words(words: [u64; 4]) -> Self {
+        let mut digest = Self::ZERO;
+        for (chunk, word) in digest.0.chunks_exact_mut(8).zip(words) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    /// Returns the digest as 64-bit words, most significant word first.
+    pub fn to_be_words(&self) -> [u64; 4] {
+        let mut words = [0; 4];
+        for (word, chunk) in words.iter_mut().zip(self.0.chunks_exact(8)) {
+            *word = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+        words
+    }
+
+    /// Creates a digest from 64-bit words, least significant word first. This
+    /// is the limb order used by most big integer libraries.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest::from_le_words([0x0102, 0, 0, 0]);
+    /// assert_eq!(digest, Digest::from_be_words([0, 0, 0, 0x0102]));
+    /// assert_eq!(digest.to_le_words(), [0x0102, 0, 0, 0]);
+    /// ```
+    pub fn from_le_words(mut words: [u64; 4]) -> Self {
+        words.reverse();
+        Self::from_be_words(words)
+    }
+
+    /// Returns the digest as 64-bit words, least significant word first.
+    pub fn to_le_words(&self) -> [u64; 4] {
+        let mut words = self.to_be_words();
+        words.reverse();
+        words
+    }
+
+    /// Creates a digest with its low 8 bytes set to the big-endian
+    /// representation of a `u64` value, and all other bytes set to zero. This
+    /// matches `H256::from_low_u64_be` from `primitive-types`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest::from_low_u64_be(1);
+    /// assert_eq!(digest, Digest::ZERO.with_byte(31, 1));
+    /// assert_eq!(digest.to_low_u64_be(), 1);
+    /// ```
+    pub fn from_low_u64_be(value: u64) -> Self {
+        Self::ZERO.with_low_bytes(value.to_be_bytes())
+    }
+
+    /// Creates a digest with its low 8 bytes set to the little-endian
+    /// representation of a `u64` value, and all other bytes set to zero. This
+    /// matches `H256::from_low_u64_le` from `primitive-types`.
+    pub fn from_low_u64_le(value: u64) -> Self {
+        Self::ZERO.with_low_bytes(value.to_le_bytes())
+    }
+
+    /// Creates a digest with its low 8 bytes set to the native-endian
+    /// representation of a `u64` value, and all other bytes set to zero. This
+    /// matches `H256::from_low_u64_ne` from `primitive-types`.
+    pub fn from_low_u64_ne(value: u64) -> Self {
+        Self::ZERO.with_low_bytes(value.to_ne_bytes())
+    }
+
+    /// Returns the low 8 bytes of the digest as a big-endian `u64` value.
+    pub fn to_low_u64_be(&self) -> u64 {
+        u64::from_be_bytes(self.low_bytes())
+    }
+
+    /// Returns the low 8 bytes of the digest as a little-endian `u64` value.
+    pub fn to_low_u64_le(&self) -> u64 {
+        u64::from_le_bytes(self.low_bytes())
+    }
+
+    /// Returns the low 8 bytes of the digest as a native-endian `u64` value.
+    pub fn to_low_u64_ne(&self) -> u64 {
+        u64::from_ne_bytes(self.low_bytes())
+    }
+
+    /// Returns the digest with its low 8 bytes replaced.
+    fn with_low_bytes(mut self, bytes: [u8; 8]) -> Self {
+        self.0[24..].copy_from_slice(&bytes);
+        self
+    }
+
+    /// Returns the low 8 bytes of the digest.
+    fn low_bytes(&self) -> [u8; 8] {
+        self.0[24..].try_into().unwrap()
+    }
+
+    /// Creates a digest from a pair of 128-bit integers, most significant
+    /// first.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest::from_be_u128_pair((1, 2));
+    /// assert_eq!(digest, Digest::from_be_words([0, 1, 0, 2]));
+    /// assert_eq!(digest.to_be_u128_pair(), (1, 2));
+    /// ```
+    pub fn from_be_u128_pair((high, low): (u128, u128)) -> Self {
+        let mut digest = Self::ZERO;
+        digest.0[..16].copy_from_slice(&high.to_be_bytes());
+        digest.0[16..].copy_from_slice(&low.to_be_bytes());
+        digest
+    }
+
+    /// Returns the digest as a pair of 128-bit integers, most significant
+    /// first.
+    pub fn to_be_u128_pair(&self) -> (u128, u128) {
+        (
+            u128::from_be_bytes(self.0[..16].try_into().unwrap()),
+            u128::from_be_bytes(self.0[16..].try_into().unwrap()),
+        )
+    }
+
+    /// Adds two digests as 256-bit big-endian integers, returning the
+    /// wrapped result along with whether or not an overflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::from_low_u64_be(1).overflowing_add(Digest::from_low_u64_be(2)),
+    ///     (Digest::from_low_u64_be(3), false),
+    /// );
+    /// assert_eq!(
+    ///     Digest::MAX.overflowing_add(Digest::from_low_u64_be(1)),
+    ///     (Digest::ZERO, true),
+    /// );
+    /// ```
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (a, b) = (self.to_le_words(), rhs.to_le_words());
+        let mut words = [0; 4];
+        let mut carry = false;
+        for (word, (a, b)) in words.iter_mut().zip(a.into_iter().zip(b)) {
+            let (sum, c0) = a.overflowing_add(b);
+            let (sum, c1) = sum.overflowing_add(carry as u64);
+            *word = sum;
+            carry = c0 | c1;
+        }
+        (Self::from_le_words(words), carry)
+    }
+
+    /// Adds two digests as 256-bit big-endian integers, returning `None` on
+    /// overflow. This is useful for computing Solidity storage slots.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let slot = Digest::from_low_u64_be(0xff);
+    /// assert_eq!(
+    ///     slot.checked_add(Digest::from_low_u64_be(1)),
+    ///     Some(Digest::from_low_u64_be(0x100)),
+    /// );
+    /// assert_eq!(Digest::MAX.checked_add(Digest::from_low_u64_be(1)), None);
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_add(rhs) {
+            (result, false) => Some(result),
+            (_, true) => None,
+        }
+    }
+
+    /// Adds two digests as 256-bit big-endian integers, wrapping around on
+    /// overflow.
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        self.overflowing_add(rhs).0
+    }
+
+    /// Subtracts two digests as 256-bit big-endian integers, returning the
+    /// wrapped result along with whether or not an underflow occurred.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::ZERO.overflowing_sub(Digest::from_low_u64_be(1)),
+    ///     (Digest::MAX, true),
+    /// );
+    /// ```
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (a, b) = (self.to_le_words(), rhs.to_le_words());
+        let mut words = [0; 4];
+        let mut borrow = false;
+        for (word, (a, b)) in words.iter_mut().zip(a.into_iter().zip(b)) {
+            let (diff, b0) = a.overflowing_sub(b);
+            let (diff, b1) = diff.overflowing_sub(borrow as u64);
+            *word = diff;
+            borrow = b0 | b1;
+        }
+        (Self::from_le_words(words), borrow)
+    }
+
+    /// Subtracts two digests as 256-bit big-endian integers, returning `None`
+    /// on underflow.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::from_low_u64_be(0x100).checked_sub(Digest::from_low_u64_be(1)),
+    ///     Some(Digest::from_low_u64_be(0xff)),
+    /// );
+    /// assert_eq!(Digest::ZERO.checked_sub(Digest::from_low_u64_be(1)), None);
+    /// ```
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_sub(rhs) {
+            (result, false) => Some(result),
+            (_, true) => None,
+        }
+    }
+
+    /// Subtracts two digests as 256-bit big-endian integers, wrapping around
+    /// on underflow.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_round_trips() {
+        let digest = Digest::from_slice(&(0..32).collect::<Vec<_>>());
+        assert_eq!(
+            digest.to_be_words(),
+            [
+                0x0001020304050607,
+                0x08090a0b0c0d0e0f,
+                0x1011121314151617,
+                0x18191a1b1c1d1e1f,
+            ],
+        );
+        assert_eq!(Digest::from_be_words(digest.to_be_words()), digest);
+        assert_eq!(Digest::from_le_words(digest.to_le_words()), digest);
+        assert_eq!(Digest::from_be_u128_pair(digest.to_be_u128_pair()), digest);
+    }
+
+    #[test]
+    fn low_u64_conversions() {
+        let value = 0x0102030405060708;
+        let digest = Digest::from_low_u64_le(value);
+        assert_eq!(digest[24..], [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(digest.to_low_u64_le(), value);
+        assert_eq!(digest.to_low_u64_be(), value.swap_bytes());
+        assert_eq!(Digest::from_low_u64_ne(value).to_low_u64_ne(), value);
+    }
+
+    #[test]
+    fn arithmetic_carries_across_words() {
+        let a = Digest::from_be_words([0, 0, u64::MAX, u64::MAX]);
+        let one = Digest::from_low_u64_be(1);
+        assert_eq!(a.wrapping_add(one), Digest::from_be_words([0, 1, 0, 0]));
+        assert_eq!(Digest::from_be_words([0, 1, 0, 0]).wrapping_sub(one), a,);
+        assert_eq!(
+            Digest::MAX.wrapping_add(Digest::MAX),
+            Digest::MAX.wrapping_sub(one)
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Module implementing reading and writing raw digests from I/O streams.
+
+use crate::Digest;
+use std::io::{self, Read, Write};
+#[cfg(feature = "tokio")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+impl Digest {
+    /// Reads a digest as exactly 32 raw bytes from a reader.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let mut reader = &[0xee; 40][..];
+    /// assert_eq!(Digest::read_from(&mut reader).unwrap(), Digest([0xee; 32]));
+    /// assert_eq!(reader.len(), 8);
+    /// assert!(Digest::read_from(&mut reader).is_err());
+    /// ```
+    pub fn read_from(reader: &mut impl Read) -> io::Result<Self> {
+        let mut digest = Self::default();
+        reader.read_exact(&mut digest.0)?;
+        Ok(digest)
+    }
+
+    /// Writes a digest as 32 raw bytes to a writer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let mut buffer = Vec::new();
+    /// Digest([0xee; 32]).write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, [0xee; 32]);
+    /// ```
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+
+    /// Asynchronously reads a digest as exactly 32 raw bytes from a reader.
+    #[cfg(feature = "tokio")]
+    pub async fn read_from_async(reader: &mut (impl AsyncRead + Unpin)) -> io::Result<Self> {
+        let mut digest = Self::default();
+        reader.read_exact(&mut digest.0).await?;
+        Ok(digest)
+    }
+
+    /// Asynchronously writes a digest as 32 raw bytes to a writer.
+    #[cfg(feature = "tokio")]
+    pub async fn write_to_async(&self, writer: &mut (impl AsyncWrite + Unpin)) -> io::Result<()> {
+        writer.write_all(&self.0).await
+    }
+}
+
+#[cfg(all(test, feature = "tokio"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn async_round_trip() {
+        let mut buffer = Vec::new();
+        Digest([0xee; 32])
+            .write_to_async(&mut buffer)
+            .await
+            .unwrap();
+        Digest([0xaa; 32])
+            .write_to_async(&mut buffer)
+            .await
+            .unwrap();
+
+        let mut reader = &buffer[..48];
+        assert_eq!(
+            Digest::read_from_async(&mut reader).await.unwrap(),
+            Digest([0xee; 32]),
+        );
+        assert_eq!(
+            Digest::read_from_async(&mut reader)
+                .await
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::UnexpectedEof,
+        );
+    }
+}
@@ -1,8 +1,13 @@
 //! Module implementing Ethereum Keccak-256 hashing utilities.
 
-use crate::Digest;
-use core::fmt::{self, Debug, Formatter};
-use sha3::Digest as _;
+use crate::{
+    sponge::{self, Sponge},
+    Digest,
+};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    hash::{BuildHasherDefault, Hash, Hasher},
+};
 
 /// A Keccak-256 hasher.
 ///
@@ -51,13 +56,34 @@ use sha3::Digest as _;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Clone, Default)]
-pub struct Keccak(sha3::Keccak256);
+///
+/// The hasher can also be extended with bytes from iterators:
+///
+/// ```
+/// # use ethdigest::{Digest, Keccak};
+/// let mut hasher = Keccak::new();
+/// hasher.extend("Hello Ethereum!".bytes().filter(|b| !b.is_ascii_whitespace()));
+/// assert_eq!(hasher.finalize_reset(), Digest::of("HelloEthereum!"));
+///
+/// hasher.extend(["Hello ", "Ethereum!"].map(str::as_bytes));
+/// assert_eq!(hasher.finalize(), Digest::of("Hello Ethereum!"));
+/// ```
+#[derive(Clone)]
+pub struct Keccak(Sponge<RATE>);
+
+/// The Keccak-256 sponge rate in bytes.
+const RATE: usize = 136;
+
+/// The current version of the exported hasher state format.
+const STATE_VERSION: u8 = 1;
 
 impl Keccak {
+    /// The length of an exported hasher state in bytes.
+    pub const STATE_LEN: usize = 1 + 8 + 200 + RATE;
+
     /// Creates a new [`Keccak`] istance.
     pub fn new() -> Self {
-        Self::default()
+        Self(Sponge::new())
     }
 
     /// Processes new data and updates the hasher.
@@ -65,9 +91,337 @@ impl Keccak {
         self.0.update(data.as_ref());
     }
 
+    /// Processes new data and returns the updated hasher. This allows digests
+    /// to be computed in expression position.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let digest = Keccak::new()
+    ///     .chain_update("Hello ")
+    ///     .chain_update("Ethereum!")
+    ///     .finalize();
+    /// assert_eq!(digest, Digest::of("Hello Ethereum!"));
+    /// ```
+    #[must_use]
+    pub fn chain_update(mut self, data: impl AsRef<[u8]>) -> Self {
+        self.update(data);
+        self
+    }
+
+    /// Processes bytes from an iterator and updates the hasher. This allows
+    /// hashing data that is not available as a contiguous slice, without
+    /// collecting it first.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let mut hasher = Keccak::new();
+    /// hasher.update_iter("Hello Ethereum!".bytes().rev());
+    /// assert_eq!(hasher.finalize(), Digest::of("!muerehtE olleH"));
+    /// ```
+    pub fn update_iter(&mut self, bytes: impl IntoIterator<Item = u8>) {
+        // Buffer bytes into blocks of the Keccak-256 rate to avoid updating the
+        // hasher one byte at a time.
+        let mut buffer = [0; RATE];
+        let mut len = 0;
+        for byte in bytes {
+            buffer[len] = byte;
+            len += 1;
+            if len == buffer.len() {
+                self.update(buffer);
+                len = 0;
+            }
+        }
+        self.update(&buffer[..len]);
+    }
+
+    /// Processes a 32-byte EVM word and updates the hasher.
+    pub fn update_word(&mut self, word: &[u8; 32]) {
+        self.update(word);
+    }
+
+    /// Processes an unsigned integer as a left-padded 32-byte big-endian EVM
+    /// word, matching Solidity's layout for `uint` values.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let mut hasher = Keccak::new();
+    /// hasher.update_uint(0xee);
+    /// let mut word = [0; 32];
+    /// word[31] = 0xee;
+    /// assert_eq!(hasher.finalize(), Digest::of(word));
+    /// ```
+    pub fn update_uint(&mut self, value: u64) {
+        let mut word = [0; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        self.update_word(&word);
+    }
+
+    /// Processes a 20-byte address as a left-padded 32-byte EVM word, matching
+    /// Solidity's layout for `address` values.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let mut hasher = Keccak::new();
+    /// hasher.update_address([0xee; 20]);
+    /// let mut word = [0xee; 32];
+    /// word[..12].fill(0);
+    /// assert_eq!(hasher.finalize(), Digest::of(word));
+    /// ```
+    pub fn update_address(&mut self, address: [u8; 20]) {
+        let mut word = [0; 32];
+        word[12..].copy_from_slice(&address);
+        self.update_word(&word);
+    }
+
+    /// Processes a boolean as a 32-byte EVM word, matching Solidity's layout
+    /// for `bool` values.
+    pub fn update_bool(&mut self, value: bool) {
+        self.update_uint(value.into());
+    }
+
+    /// Returns the total number of bytes processed by the hasher since it was
+    /// created or last reset.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Keccak;
+    /// let mut hasher = Keccak::new();
+    /// hasher.update("Hello ");
+    /// hasher.update_uint(42);
+    /// assert_eq!(hasher.bytes_processed(), 38);
+    /// hasher.finalize_reset();
+    /// assert_eq!(hasher.bytes_processed(), 0);
+    /// ```
+    pub fn bytes_processed(&self) -> u64 {
+        self.0.len
+    }
+
+    /// Exports the current hasher state, so that hashing can be resumed later
+    /// with [`Keccak::from_state_bytes`], possibly in a different process.
+    ///
+    /// The exported state is versioned and has the following layout:
+    /// - 1 byte format version, currently `1`
+    /// - 8 byte big-endian number of bytes processed so far
+    /// - 200 byte Keccak-f\[1600\] state, as 25 little-endian 64-bit lanes
+    /// - 136 byte buffer of pending input that has not yet been absorbed into
+    ///   the state, padded with zeros
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let mut hasher = Keccak::new();
+    /// hasher.update("Hello ");
+    /// let state = hasher.to_state_bytes();
+    ///
+    /// let mut hasher = Keccak::from_state_bytes(&state).unwrap();
+    /// hasher.update("Ethereum!");
+    /// assert_eq!(hasher.finalize(), Digest::of("Hello Ethereum!"));
+    /// ```
+    pub fn to_state_bytes(&self) -> [u8; Self::STATE_LEN] {
+        let mut bytes = [0; Self::STATE_LEN];
+        bytes[0] = STATE_VERSION;
+        bytes[1..9].copy_from_slice(&self.0.len.to_be_bytes());
+        for (chunk, lane) in bytes[9..209].chunks_exact_mut(8).zip(&self.0.state) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        let pos = self.0.pos();
+        bytes[209..][..pos].copy_from_slice(&self.0.buffer[..pos]);
+        bytes
+    }
+
+    /// Creates a hasher from a state exported with [`Keccak::to_state_bytes`].
+    pub fn from_state_bytes(bytes: &[u8]) -> Result<Self, InvalidStateError> {
+        if bytes.len() != Self::STATE_LEN {
+            return Err(InvalidStateError::InvalidLength { len: bytes.len() });
+        }
+        if bytes[0] != STATE_VERSION {
+            return Err(InvalidStateError::UnsupportedVersion { version: bytes[0] });
+        }
+
+        let mut sponge = Sponge::new();
+        sponge.len = u64::from_be_bytes(bytes[1..9].try_into().unwrap());
+        for (lane, chunk) in sponge.state.iter_mut().zip(bytes[9..209].chunks_exact(8)) {
+            *lane = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        let pos = sponge.pos();
+        if bytes[209 + pos..].iter().any(|&b| b != 0) {
+            return Err(InvalidStateError::NonZeroPadding);
+        }
+        sponge.buffer[..pos].copy_from_slice(&bytes[209..][..pos]);
+        Ok(Self(sponge))
+    }
+
+    /// Returns the digest of all data processed so far, without consuming or
+    /// resetting the hasher. This is useful for emitting intermediate
+    /// checkpoints while hashing a stream.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let mut hasher = Keccak::new();
+    /// hasher.update("Hello ");
+    /// assert_eq!(hasher.current(), Digest::of("Hello "));
+    /// hasher.update("Ethereum!");
+    /// assert_eq!(hasher.current(), Digest::of("Hello Ethereum!"));
+    /// ```
+    pub fn current(&self) -> Digest {
+        self.clone().finalize()
+    }
+
+    /// Returns a snapshot of the hasher that can be updated independently.
+    ///
+    /// This is useful for hashing many messages that share a common prefix:
+    /// the prefix is absorbed once, and the hasher is then forked for each
+    /// suffix. Forking only copies the fixed-size hasher state, so it is much
+    /// cheaper than re-hashing a long prefix.
+    ///
+    /// # Examples
+    ///
+    /// Grinding a `CREATE2` salt:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let deployer = [0x11; 20];
+    /// let init_code_hash = Digest::of("init code");
+    ///
+    /// let mut prefix = Keccak::new();
+    /// prefix.update([0xff]);
+    /// prefix.update(deployer);
+    ///
+    /// // Find a salt for which the deployed address starts with a zero byte.
+    /// let salt = (0..)
+    ///     .map(Digest::from_low_u64_be)
+    ///     .find(|salt| {
+    ///         let hash = prefix
+    ///             .fork()
+    ///             .chain_update(salt)
+    ///             .chain_update(init_code_hash)
+    ///             .finalize();
+    ///         hash[12] == 0
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let mut hasher = Keccak::new();
+    /// hasher.update([0xff]);
+    /// hasher.update(deployer);
+    /// hasher.update(salt);
+    /// hasher.update(init_code_hash);
+    /// assert_eq!(hasher.finalize()[12], 0);
+    /// ```
+    #[must_use]
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
     /// Retrieve the resulting digest.
-    pub fn finalize(self) -> Digest {
-        Digest(self.0.finalize().into())
+    pub fn finalize(mut self) -> Digest {
+        self.finalize_reset()
+    }
+
+    /// Retrieve the resulting digest and reset the hasher to its initial
+    /// state, so that it can be reused.
+    pub fn finalize_reset(&mut self) -> Digest {
+        let mut digest = Digest::default();
+        self.finalize_into_reset(&mut digest);
+        digest
+    }
+
+    /// Writes the resulting digest into an existing output, allowing output
+    /// buffers to be reused. Since [`Digest`] dereferences to a 32-byte
+    /// array, this accepts both `&mut Digest` and `&mut [u8; 32]` outputs.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let mut digest = Digest::ZERO;
+    /// let mut hasher = Keccak::new();
+    /// hasher.update("Hello Ethereum!");
+    /// hasher.finalize_into(&mut digest);
+    /// assert_eq!(digest, Digest::of("Hello Ethereum!"));
+    /// ```
+    pub fn finalize_into(mut self, out: &mut [u8; 32]) {
+        self.finalize_into_reset(out);
+    }
+
+    /// Writes the resulting digest into an existing output and resets the
+    /// hasher to its initial state, so that it can be reused.
+    pub fn finalize_into_reset(&mut self, out: &mut [u8; 32]) {
+        self.0.pad(0x01);
+        self.0.squeeze(out);
+        self.reset();
+    }
+
+    /// Resets the hasher to its initial state, discarding any processed
+    /// data.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, Keccak};
+    /// let mut hasher = Keccak::new();
+    /// hasher.update("discarded");
+    /// hasher.reset();
+    /// hasher.update("Hello Ethereum!");
+    /// assert_eq!(hasher.finalize(), Digest::of("Hello Ethereum!"));
+    /// ```
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for Keccak {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<u8> for Keccak {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        self.update_iter(iter);
+    }
+}
+
+impl<'a> Extend<&'a u8> for Keccak {
+    fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+        self.update_iter(iter.into_iter().copied());
+    }
+}
+
+impl<'a> Extend<&'a [u8]> for Keccak {
+    fn extend<I: IntoIterator<Item = &'a [u8]>>(&mut self, iter: I) {
+        for data in iter {
+            self.update(data);
+        }
     }
 }
 
@@ -84,10 +438,356 @@ impl fmt::Write for Keccak {
     }
 }
 
+/// Represents an error importing an exported [`Keccak`] hasher state.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InvalidStateError {
+    /// The exported state has an invalid length.
+    InvalidLength { len: usize },
+    /// The exported state has an unsupported format version.
+    UnsupportedVersion { version: u8 },
+    /// The exported state has non-zero bytes past the end of the buffered
+    /// input, indicating that it is corrupted.
+    NonZeroPadding,
+}
+
+impl Display for InvalidStateError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidLength { len } => write!(
+                f,
+                "invalid hasher state length {len}, expected {}",
+                Keccak::STATE_LEN,
+            ),
+            Self::UnsupportedVersion { version } => {
+                write!(f, "unsupported hasher state version {version}")
+            }
+            Self::NonZeroPadding => write!(f, "non-zero hasher state buffer padding"),
+        }
+    }
+}
+
+impl core::error::Error for InvalidStateError {}
+
+/// An adapter for hashing values implementing [`Hash`] with Keccak-256.
+///
+/// Integers are always written as little-endian bytes, with `usize` and
+/// `isize` widened to 64 bits, so that writes hash the same on any platform.
+/// However, the resulting digest still depends on the [`Hash`] implementation
+/// of the value, which is not guaranteed to be stable across Rust or crate
+/// versions. Digests computed this way are therefore only suitable as
+/// fingerprints and should not be persisted; use [`Hashable`](crate::Hashable)
+/// for a fully specified encoding.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, KeccakHasher};
+/// # use std::hash::Hash;
+/// let mut hasher = KeccakHasher::new();
+/// (1_u8, "two").hash(&mut hasher);
+/// assert_eq!(hasher.finalize(), Digest::of_hash(&(1_u8, "two")));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct KeccakHasher(Keccak);
+
+impl KeccakHasher {
+    /// Creates a new [`KeccakHasher`] instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieve the resulting digest.
+    pub fn finalize(self) -> Digest {
+        self.0.finalize()
+    }
+}
+
+impl Hasher for KeccakHasher {
+    fn finish(&self) -> u64 {
+        let digest = self.clone().finalize();
+        u64::from_be_bytes(digest[..8].try_into().unwrap())
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.write(&[i]);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i8(&mut self, i: i8) {
+        self.write_u8(i as u8);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32);
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128);
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+/// A [`BuildHasher`](core::hash::BuildHasher) for [`KeccakHasher`].
+///
+/// Unlike the randomly seeded hasher used by default for standard library
+/// collections, this always produces the same hashes for the same writes, on
+/// any platform and across crate versions, as integer writes use a fixed
+/// little-endian encoding. This makes it suitable for
+/// deterministic fingerprinting and reproducible hash-based collections,
+/// subject to the [`Hash`] implementation caveats documented on
+/// [`KeccakHasher`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::BuildKeccakHasher;
+/// # use std::{collections::HashMap, hash::{BuildHasher, Hasher}};
+/// let mut hasher = BuildKeccakHasher::default().build_hasher();
+/// hasher.write(b"Hello Ethereum!");
+/// assert_eq!(hasher.finish(), 0x67e083fb08738b8d);
+///
+/// let mut map = HashMap::with_hasher(BuildKeccakHasher::default());
+/// map.insert("answer", 42);
+/// assert_eq!(map["answer"], 42);
+/// ```
+pub type BuildKeccakHasher = BuildHasherDefault<KeccakHasher>;
+
+impl Digest {
+    /// Creates a digest by hashing the concatenation of multiple parts,
+    /// without having to allocate a buffer for them. This is equivalent to
+    /// Solidity's `keccak256(abi.encodePacked(...))` for byte parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::of_many(["Hello ", "Ethereum", "!"]),
+    ///     Digest::of("Hello Ethereum!"),
+    /// );
+    /// ```
+    pub fn of_many(parts: impl IntoIterator<Item = impl AsRef<[u8]>>) -> Self {
+        let mut hasher = Keccak::new();
+        for part in parts {
+            hasher.update(part);
+        }
+        hasher.finalize()
+    }
+
+    /// Creates a digest by hashing a value with its [`Hash`] implementation.
+    ///
+    /// See [`KeccakHasher`] for caveats on the stability of the resulting
+    /// digest.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::of_hash(&[1, 2, 3]), Digest::of_hash(&[1, 2, 3]));
+    /// assert_ne!(Digest::of_hash(&[1, 2, 3]), Digest::of_hash(&[3, 2, 1]));
+    /// ```
+    pub fn of_hash(value: &(impl Hash + ?Sized)) -> Self {
+        let mut hasher = KeccakHasher::new();
+        value.hash(&mut hasher);
+        hasher.finalize()
+    }
+
+    /// Computes the Keccak-256 digest of the concatenation of two digests.
+    /// This is the parent node operation for Merkle trees of digests.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let (a, b) = (Digest([0xaa; 32]), Digest([0xbb; 32]));
+    /// assert_eq!(a.chain(&b), Digest::of([a.0, b.0].concat()));
+    /// assert_ne!(a.chain(&b), b.chain(&a));
+    /// ```
+    pub fn chain(&self, other: &Digest) -> Digest {
+        let mut hasher = Keccak::new();
+        hasher.update_word(self);
+        hasher.update_word(other);
+        hasher.finalize()
+    }
+
+    /// Repeatedly hashes the digest, computing the Keccak-256 digest of the
+    /// previous output for the specified number of rounds. This is useful for
+    /// hash chains and hash onions.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let seed = Digest([0xee; 32]);
+    /// assert_eq!(seed.rehash(0), seed);
+    /// assert_eq!(seed.rehash(2), Digest::of(Digest::of(seed)));
+    /// ```
+    pub fn rehash(self, rounds: usize) -> Digest {
+        let mut lanes = [0; 4];
+        for (lane, chunk) in lanes.iter_mut().zip(self.chunks_exact(8)) {
+            *lane = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        // A digest always fits in a single block, so each round can permute a
+        // fresh sponge state with the padding already applied.
+        for _ in 0..rounds {
+            let mut state = [0; 25];
+            state[..4].copy_from_slice(&lanes);
+            state[4] = 0x01;
+            state[RATE / 8 - 1] = 0x80 << 56;
+            sponge::permute(&mut state);
+            lanes.copy_from_slice(&state[..4]);
+        }
+
+        let mut digest = Digest::default();
+        for (chunk, lane) in digest.chunks_exact_mut(8).zip(lanes) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        digest
+    }
+}
+
 #[cfg(feature = "std")]
 mod io {
     use super::Keccak;
-    use std::io::{self, Write};
+    use crate::Digest;
+    use std::{
+        fs::File,
+        io::{self, IoSlice, Read, Write},
+        path::Path,
+    };
+
+    /// The size of the buffer used for streaming files through the hasher.
+    const BUFFER_SIZE: usize = 64 * 1024;
+
+    impl Digest {
+        /// Creates a digest by hashing the contents of a file, returning it
+        /// along with the number of bytes that were hashed.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use ethdigest::Digest;
+        /// let path = std::env::temp_dir().join("ethdigest-of-file.txt");
+        /// std::fs::write(&path, "Hello Ethereum!").unwrap();
+        /// assert_eq!(
+        ///     Digest::of_file(&path).unwrap(),
+        ///     (Digest::of("Hello Ethereum!"), 15),
+        /// );
+        /// # std::fs::remove_file(&path).unwrap();
+        /// ```
+        pub fn of_file(path: impl AsRef<Path>) -> io::Result<(Self, u64)> {
+            stream(&mut File::open(path)?)
+        }
+
+        /// Creates a digest by streaming data from a reader until it is
+        /// exhausted, without reading it all into memory.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use ethdigest::Digest;
+        /// let reader = "Hello Ethereum!".as_bytes();
+        /// assert_eq!(
+        ///     Digest::of_reader(reader).unwrap(),
+        ///     Digest::of("Hello Ethereum!"),
+        /// );
+        /// ```
+        pub fn of_reader(mut reader: impl Read) -> io::Result<Self> {
+            stream(&mut reader).map(|(digest, _)| digest)
+        }
+    }
+
+    /// Streams data from a reader through a hasher, returning the digest and
+    /// the number of bytes that were hashed.
+    fn stream(reader: &mut impl Read) -> io::Result<(Digest, u64)> {
+        let mut hasher = Keccak::new();
+        let mut buffer = vec![0; BUFFER_SIZE];
+        let mut len = 0;
+        loop {
+            let n = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            hasher.update(&buffer[..n]);
+            len += n as u64;
+        }
+        Ok((hasher.finalize(), len))
+    }
+
+    impl Keccak {
+        /// Processes new data from a sequence of buffers and updates the
+        /// hasher.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use ethdigest::{Digest, Keccak};
+        /// # use std::io::IoSlice;
+        /// let mut hasher = Keccak::new();
+        /// hasher.update_vectored(&[IoSlice::new(b"Hello "), IoSlice::new(b"Ethereum!")]);
+        /// assert_eq!(hasher.finalize(), Digest::of("Hello Ethereum!"));
+        /// ```
+        pub fn update_vectored(&mut self, bufs: &[IoSlice]) {
+            for buf in bufs {
+                self.update(&**buf);
+            }
+        }
+    }
 
     impl Write for Keccak {
         fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
@@ -95,8 +795,187 @@ mod io {
             Ok(buf.len())
         }
 
+        fn write_vectored(&mut self, bufs: &[IoSlice]) -> io::Result<usize> {
+            self.update_vectored(bufs);
+            Ok(bufs.iter().map(|buf| buf.len()).sum())
+        }
+
         fn flush(&mut self) -> io::Result<()> {
             Ok(())
         }
     }
 }
+
+#[cfg(feature = "tokio")]
+mod tokio {
+    use super::Keccak;
+    use crate::Digest;
+    use ::tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite};
+    use std::{
+        io::{self, IoSlice},
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    /// The size of the buffer used for streaming data through the hasher.
+    const BUFFER_SIZE: usize = 64 * 1024;
+
+    impl Digest {
+        /// Creates a digest by asynchronously streaming data from a reader
+        /// until it is exhausted, without reading it all into memory.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use ethdigest::Digest;
+        /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+        /// let reader = "Hello Ethereum!".as_bytes();
+        /// assert_eq!(
+        ///     Digest::of_async_reader(reader).await.unwrap(),
+        ///     Digest::of("Hello Ethereum!"),
+        /// );
+        /// # });
+        /// ```
+        pub async fn of_async_reader(mut reader: impl AsyncRead + Unpin) -> io::Result<Self> {
+            let mut hasher = Keccak::new();
+            let mut buffer = vec![0; BUFFER_SIZE];
+            loop {
+                match reader.read(&mut buffer).await? {
+                    0 => break,
+                    n => hasher.update(&buffer[..n]),
+                }
+            }
+            Ok(hasher.finalize())
+        }
+    }
+
+    impl AsyncWrite for Keccak {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            _: &mut Context,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.update(buf);
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_write_vectored(
+            mut self: Pin<&mut Self>,
+            _: &mut Context,
+            bufs: &[IoSlice],
+        ) -> Poll<io::Result<usize>> {
+            self.update_vectored(bufs);
+            Poll::Ready(Ok(bufs.iter().map(|buf| buf.len()).sum()))
+        }
+
+        fn is_write_vectored(&self) -> bool {
+            true
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ::tokio::io::AsyncWriteExt;
+
+        #[::tokio::test(flavor = "current_thread")]
+        async fn async_hashing() {
+            let data = (0..=255).cycle().take(200_000).collect::<Vec<u8>>();
+            let expected = Digest::of(&data);
+            assert_eq!(Digest::of_async_reader(&data[..]).await.unwrap(), expected);
+
+            let mut hasher = Keccak::new();
+            ::tokio::io::copy(&mut &data[..], &mut hasher)
+                .await
+                .unwrap();
+            hasher.shutdown().await.unwrap();
+            assert_eq!(hasher.finalize(), expected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rehash_matches_repeated_hashing() {
+        let mut digest = Digest::of("seed");
+        for rounds in 0..10 {
+            assert_eq!(Digest::of("seed").rehash(rounds), digest);
+            digest = Digest::of(digest);
+        }
+    }
+
+    #[test]
+    fn matches_reference_implementation() {
+        use sha3::Digest as _;
+
+        let data = (0..=255).cycle().take(3 * RATE + 1).collect::<Vec<u8>>();
+        for len in 0..data.len() {
+            let expected = Digest(sha3::Keccak256::digest(&data[..len]).into());
+            assert_eq!(Digest::of(&data[..len]), expected);
+            assert_eq!(Digest::of_const(&data[..len]), expected);
+        }
+    }
+
+    #[test]
+    fn state_round_trip() {
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        for split in [0, 1, RATE - 1, RATE, RATE + 1, 999] {
+            let mut hasher = Keccak::new();
+            hasher.update(&data[..split]);
+            let mut hasher = Keccak::from_state_bytes(&hasher.to_state_bytes()).unwrap();
+            hasher.update(&data[split..]);
+            assert_eq!(hasher.finalize(), Digest::of(&data));
+        }
+
+        let mut state = Keccak::new().to_state_bytes();
+        assert_eq!(
+            Keccak::from_state_bytes(&state[1..]).unwrap_err(),
+            InvalidStateError::InvalidLength {
+                len: Keccak::STATE_LEN - 1
+            },
+        );
+        state[Keccak::STATE_LEN - 1] = 1;
+        assert_eq!(
+            Keccak::from_state_bytes(&state).unwrap_err(),
+            InvalidStateError::NonZeroPadding,
+        );
+        state[0] = 0;
+        assert_eq!(
+            Keccak::from_state_bytes(&state).unwrap_err(),
+            InvalidStateError::UnsupportedVersion { version: 0 },
+        );
+    }
+
+    #[test]
+    fn build_hasher_is_platform_independent() {
+        use core::hash::BuildHasher as _;
+
+        let hasher = BuildKeccakHasher::default();
+        assert_eq!(hasher.hash_one(1_usize), 0x30f692b256e24009);
+        assert_eq!(hasher.hash_one((1_u32, "x")), 0xb27934b45e20685e);
+    }
+
+    #[test]
+    fn extend_across_blocks() {
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        let mut hasher = Keccak::new();
+        hasher.extend(data.iter().copied());
+        assert_eq!(hasher.finalize_reset(), Digest::of(&data));
+
+        hasher.extend(&data);
+        assert_eq!(hasher.finalize(), Digest::of(&data));
+    }
+}
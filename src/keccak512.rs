@@ -0,0 +1,52 @@
+//! Module implementing the Keccak-512 hasher.
+
+use crate::sponge::{impl_hasher, Sponge};
+
+/// A Keccak-512 hasher.
+///
+/// This uses the same original Keccak padding as [`Keccak`](crate::Keccak),
+/// and produces a 64-byte output. Keccak-512 is used by Ethash and some other
+/// Ethereum tooling.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Keccak512;
+/// let output = Keccak512::new().chain_update("").finalize();
+/// assert_eq!(output[..4], [0x0e, 0xab, 0x42, 0xde]);
+/// assert_eq!(output[60..], [0x36, 0x70, 0x68, 0x0e]);
+/// ```
+#[derive(Clone)]
+pub struct Keccak512(Sponge<RATE>);
+
+/// The Keccak-512 sponge rate in bytes.
+const RATE: usize = 72;
+
+/// The original Keccak padding, without a domain separation suffix.
+const KECCAK_DOMAIN: u8 = 0x01;
+
+impl_hasher!(Keccak512, KECCAK_DOMAIN, [u8; 64], [0; 64]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_reference_implementation() {
+        use sha3::Digest as _;
+
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        for len in [0, 1, RATE - 1, RATE, RATE + 1, 1000] {
+            let mut hasher = Keccak512::new();
+            for chunk in data[..len].chunks(7) {
+                hasher.update(chunk);
+            }
+            assert_eq!(
+                hasher.finalize()[..],
+                sha3::Keccak512::digest(&data[..len])[..],
+            );
+        }
+    }
+}
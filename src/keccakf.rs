@@ -0,0 +1,132 @@
+//! Module implementing a dependency-free Keccak-f\[1600\] permutation.
+//!
+//! Everything here is a `const fn`, so that it can be used for hashing at
+//! compile time. This module is also shared with the procedural macros crate.
+
+/// The Keccak-256 sponge rate in bytes.
+const RATE: usize = 136;
+
+/// The round constants for the iota step.
+pub const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets for the rho step, in the lane order of [`PI`].
+pub const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// The lane visiting order for the combined rho and pi steps.
+pub const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Applies the Keccak-f\[1600\] permutation to a state.
+///
+/// This is always inlined so that callers can compile it with additional
+/// target features.
+#[inline(always)]
+pub const fn f1600(mut a: [u64; 25]) -> [u64; 25] {
+    let mut round = 0;
+    while round < 24 {
+        // Theta
+        let mut c = [0; 5];
+        let mut x = 0;
+        while x < 5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+            x += 1;
+        }
+        x = 0;
+        while x < 5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            let mut y = 0;
+            while y < 25 {
+                a[y + x] ^= d;
+                y += 5;
+            }
+            x += 1;
+        }
+
+        // Rho and pi
+        let mut last = a[1];
+        let mut i = 0;
+        while i < 24 {
+            let lane = a[PI[i]];
+            a[PI[i]] = last.rotate_left(RHO[i]);
+            last = lane;
+            i += 1;
+        }
+
+        // Chi
+        let mut y = 0;
+        while y < 25 {
+            let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+            let mut x = 0;
+            while x < 5 {
+                a[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+                x += 1;
+            }
+            y += 5;
+        }
+
+        // Iota
+        a[0] ^= RC[round];
+        round += 1;
+    }
+    a
+}
+
+/// Computes the Keccak-256 hash of some data.
+pub const fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut state = [0; 25];
+    let mut pos = 0;
+    while data.len() - pos >= RATE {
+        let mut i = 0;
+        while i < RATE {
+            state[i / 8] ^= (data[pos + i] as u64) << (8 * (i % 8));
+            i += 1;
+        }
+        state = f1600(state);
+        pos += RATE;
+    }
+
+    let mut i = 0;
+    while pos + i < data.len() {
+        state[i / 8] ^= (data[pos + i] as u64) << (8 * (i % 8));
+        i += 1;
+    }
+    state[i / 8] ^= 0x01 << (8 * (i % 8));
+    state[RATE / 8 - 1] ^= 0x80 << 56;
+    state = f1600(state);
+
+    let mut output = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        output[i] = (state[i / 8] >> (8 * (i % 8))) as u8;
+        i += 1;
+    }
+    output
+}
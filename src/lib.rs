@@ -7,35 +7,200 @@
 //!
 //! This crate supports the following features:
 //! - **_default_ `std`**: Additional integration with Rust standard library
-//! types. Notably, this includes `std::error::Error` implementation on the
-//! [`ParseDigestError`] and conversions from `Vec<u8>`.
-//! - **`keccak`**: Include Keccak-256 hasing utilities (provided by the
-//! [`sha3`] crate).
+//!   types. Notably, this includes bulk formatting and reading and writing raw
+//!   digests from I/O streams. Implies `alloc`.
+//! - **`alloc`**: Integration with heap-allocated types for `no_std` targets
+//!   with an allocator, including conversions from `Vec<u8>` and bulk parsing.
+//! - **`asm`**: Use the CRYPTOGAMS assembly Keccak-f\[1600\] permutation
+//!   used by OpenSSL (via the `sha3-asm` crate). This requires a C compiler
+//!   and Perl at build time, and takes precedence over other backends. Implies
+//!   `keccak`.
+//! - **`base64`**: Base64 encoding and decoding of digests, with both the
+//!   standard and URL-safe alphabets.
+//! - **`bytemuck`**: Implements [`bytemuck`](::bytemuck) traits for digests,
+//!   allowing zero-copy casting between slices of digests and bytes.
+//! - **`clap`**: Implements [`clap`](::clap) value parsing for digests, with
+//!   error messages that suggest fixes for common mistakes. With `keccak`, also
+//!   provides a [`HashAlgorithm`] value enum for selecting a hash function.
+//! - **`cli`**: Builds an `ethdigest` command line utility for hashing files,
+//!   normalizing digests and computing selectors, topics and ENS namehashes.
+//! - **`digest`**: Implements the RustCrypto [`digest`](::digest) traits for
+//!   the [`Keccak`] hasher, for use with generic hashing code.
+//! - **`fingerprint`**: Adds "drunken bishop" visual fingerprints of digests
+//!   for human verification.
+//! - **`getrandom`**: Random digest generation from the operating system's
+//!   random number generator with the [`getrandom`](::getrandom) crate, for
+//!   `no_std` environments where `rand` is too heavy.
+//! - **`keccak`**: Include Keccak-256 hasing utilities. This uses a
+//!   dependency-free Keccak-f\[1600\] permutation by default, which detects
+//!   and uses the fastest available instructions at runtime on x86 with `std`.
+//!   It can be swapped out with the `asm`, `rustcrypto` or `tiny-keccak`
+//!   features.
 //! - **`macros`**: Adds a [`digest`] procedural macro for compile-time
-//! digest literals and a [`keccak`] procedural macro for compile-time hashing.
+//!   digest literals and a [`keccak`] procedural macro for compile-time hashing.
+//! - **`miette`**: Implements [`miette::Diagnostic`](::miette::Diagnostic) for
+//!   [`ParseDigestError`], so that parsing errors can be rendered pointing at
+//!   the offending input.
+//! - **`mmap`**: Hashing of large files by memory mapping them with the
+//!   [`memmap2`] crate.
+//! - **`multiformats`**: Base58, Keccak-256 multihash and CIDv1 encodings for
+//!   bridging digests into IPFS tooling.
+//! - **`precompiles`**: Outputs of the EVM hashing [`precompiles`], including
+//!   RIPEMD-160 with the [`ripemd`] crate. Implies `sha2`.
+//! - **`rand`**: Random digest generation with the [`rand`](::rand) crate.
+//! - **`rayon`**: Parallel hashing of many inputs with the [`rayon`](::rayon)
+//!   crate.
+//! - **`rustcrypto`**: Use the Keccak-f\[1600\] permutation provided by the
+//!   RustCrypto [`keccak`](::keccak) crate (the one used by `sha3`), which has
+//!   optimized implementations for some targets. Implies `keccak`.
 //! - **`serde`**: Serialization traits for the [`serde`](::serde) crate. Note
-//! that the implementation is very much geared towards JSON serialiazation with
-//! `serde_json`.
+//!   that the implementation is very much geared towards JSON serialiazation with
+//!   `serde_json`.
+//! - **`sha2`**: SHA-256 hashing of digests with the [`sha2`](::sha2) crate,
+//!   as used by EIP-4844 versioned hashes and the SHA-256 precompile.
+//! - **`test-vectors`**: Canonical Keccak-256 [`test_vectors`] for validating
+//!   hashing implementations in downstream test suites.
+//! - **`tiny-keccak`**: Use the Keccak-f\[1600\] permutation provided by the
+//!   [`tiny-keccak`](::tiny_keccak) crate. Implies `keccak`.
+//! - **`tokio`**: Asynchronous reading and writing of raw digests with
+//!   [`tokio`] I/O streams, as well as asynchronous Keccak-256 hashing of
+//!   streams when combined with `keccak`.
+//! - **`zeroize`**: Implements [`zeroize::Zeroize`](::zeroize::Zeroize) for
+//!   digests and adds a [`SecretDigest`] wrapper that is zeroed on drop.
 
 #![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod assert;
+#[cfg(feature = "multiformats")]
+mod base58;
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "keccak")]
+mod batch;
+mod bits;
 mod buffer;
+#[cfg(feature = "alloc")]
+mod bulk;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "clap")]
+mod clap;
+#[cfg(feature = "keccak")]
+mod cshake;
+mod decimal;
+mod decoder;
+#[cfg(feature = "digest")]
+mod digest;
+mod display;
+#[cfg(feature = "fingerprint")]
+mod fingerprint;
+#[cfg(feature = "getrandom")]
+mod getrandom;
+#[cfg(feature = "keccak")]
+mod hashable;
+mod hasher;
 mod hex;
+mod int;
+#[cfg(feature = "std")]
+mod io;
 #[cfg(feature = "keccak")]
 mod keccak;
+#[cfg(feature = "keccak")]
+mod keccak512;
+#[cfg(feature = "keccak")]
+mod keccakf;
+#[cfg(feature = "miette")]
+mod miette;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "multiformats")]
+mod multiformats;
+mod newtype;
+mod nibbles;
+#[cfg(feature = "keccak")]
+mod nist;
+mod optional;
+mod pow;
+#[cfg(feature = "precompiles")]
+pub mod precompiles;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rayon")]
+mod rayon;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "sha2")]
+mod sha2;
+#[cfg(feature = "keccak")]
+mod shake;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+mod simd;
+#[cfg(feature = "keccak")]
+mod sponge;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+pub mod types;
+#[cfg(feature = "zeroize")]
+mod zeroize;
 
+#[doc(hidden)]
+pub use crate::assert::failed as __assert_digest_failed;
+#[cfg(feature = "base64")]
+pub use crate::base64::{Base64, ParseBase64Error};
 use crate::buffer::Alphabet;
-pub use crate::hex::ParseDigestError;
+#[cfg(feature = "std")]
+pub use crate::bulk::HexWriter;
+#[cfg(feature = "alloc")]
+pub use crate::bulk::ParseManyError;
+#[cfg(feature = "clap")]
+pub use crate::clap::DigestValueParser;
+#[cfg(all(feature = "clap", feature = "keccak"))]
+pub use crate::clap::HashAlgorithm;
 #[cfg(feature = "keccak")]
-pub use crate::keccak::Keccak;
+pub use crate::cshake::{CShake128, CShake256, Kmac128, Kmac256};
+pub use crate::decimal::{Decimal, ParseDecimalError};
+pub use crate::decoder::HexDecoder;
+pub use crate::display::{Grouped, Short};
+#[cfg(feature = "fingerprint")]
+pub use crate::fingerprint::Fingerprint;
+#[cfg(feature = "keccak")]
+pub use crate::hashable::Hashable;
+pub use crate::hasher::{BuildDigestHasher, DigestHasher};
+#[cfg(feature = "std")]
+pub use crate::hasher::{DigestMap, DigestSet};
+pub use crate::hex::{ParseDigestError, PrefixPolicy, Suggestion};
+#[cfg(feature = "keccak")]
+pub use crate::keccak::{BuildKeccakHasher, InvalidStateError, Keccak, KeccakHasher};
+#[cfg(feature = "keccak")]
+pub use crate::keccak512::Keccak512;
+#[cfg(feature = "multiformats")]
+pub use crate::multiformats::{Base58, Cid, CidBytes, ParseMultiformatError};
+pub use crate::nibbles::Nibbles;
+#[cfg(feature = "keccak")]
+pub use crate::nist::{Sha3_256, Sha3_512};
+pub use crate::optional::OptionalDigest;
+#[cfg(feature = "sha2")]
+pub use crate::sha2::Sha256;
+#[cfg(feature = "keccak")]
+pub use crate::shake::{Shake128, Shake256, ShakeReader};
+#[cfg(feature = "zeroize")]
+pub use crate::zeroize::SecretDigest;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use ::serde as __serde;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::{
     array::{IntoIter, TryFromSliceError},
-    fmt::{self, Debug, Display, Formatter, LowerHex, UpperHex},
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex},
     ops::{Deref, DerefMut},
     slice::Iter,
-    str::FromStr,
+    str::{self, FromStr},
 };
 
 /// Procedural macro to create Ethereum digest values from string literals that
@@ -88,11 +253,613 @@ pub use ethdigest_macros::keccak;
 pub struct Digest(pub [u8; 32]);
 
 impl Digest {
+    /// The digest with all bytes set to zero.
+    pub const ZERO: Self = Self::repeat_byte(0);
+
+    /// The digest with all bytes set to `0xff`.
+    pub const MAX: Self = Self::repeat_byte(0xff);
+
+    /// The Keccak-256 digest of the empty input. This is the code hash of
+    /// accounts without code.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "keccak")] {
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::KECCAK_EMPTY, Digest::of([]));
+    /// # }
+    /// ```
+    pub const KECCAK_EMPTY: Self =
+        Self::from_hex("0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+
+    /// The Keccak-256 digest of the RLP-encoded empty list. This is the
+    /// ommers hash of blocks without ommers.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "keccak")] {
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::EMPTY_LIST_HASH, Digest::of([0xc0]));
+    /// # }
+    /// ```
+    pub const EMPTY_LIST_HASH: Self =
+        Self::from_hex("0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347");
+
+    /// The root hash of an empty Merkle-Patricia trie, that is the Keccak-256
+    /// digest of the RLP-encoded empty string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "keccak")] {
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::EMPTY_ROOT_HASH, Digest::of([0x80]));
+    /// # }
+    /// ```
+    pub const EMPTY_ROOT_HASH: Self =
+        Self::from_hex("0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421");
+
+    /// Creates a digest from a 32-byte array. This can be used in `const`
+    /// contexts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// static DIGEST: Digest = Digest::new([0xee; 32]);
+    /// assert_eq!(DIGEST, Digest([0xee; 32]));
+    /// ```
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Creates a digest with all bytes set to the specified value. This can
+    /// be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// const DIGEST: Digest = Digest::repeat_byte(0xee);
+    /// assert_eq!(DIGEST, Digest([0xee; 32]));
+    /// ```
+    pub const fn repeat_byte(byte: u8) -> Self {
+        Self([byte; 32])
+    }
+
+    /// Creates a digest from a hex string with an optional `0x` prefix. This
+    /// can be used in `const` contexts to declare digest literals without the
+    /// `macros` feature.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the string is not a valid hex digest. When used
+    /// in a `const` context, this results in a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// const DIGEST: Digest =
+    ///     Digest::from_hex("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee");
+    /// assert_eq!(DIGEST, Digest([0xee; 32]));
+    /// ```
+    ///
+    /// Invalid digests in `const` contexts fail to compile:
+    ///
+    /// ```compile_fail
+    /// # use ethdigest::Digest;
+    /// const DIGEST: Digest = Digest::from_hex("0xee");
+    /// ```
+    pub const fn from_hex(s: &str) -> Self {
+        Self(hex::decode_const(s))
+    }
+
     /// Creates a digest from a slice.
     ///
     /// # Panics
     ///
-    /// This method panics if the length of the slice is not 32 bytes.
+    /// This method panics if the length of the slice is not 32 bytes. Use
+    /// [`Digest::try_from_slice`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let buffer = (0..255).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     Digest::from_slice(&buffer[0..32]),
+    ///     Digest([
+    ///         0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+    ///         0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    ///         0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+    ///         0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+    ///     ]),
+    /// );
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Self {
+        slice.try_into().unwrap()
+    }
+
+    /// Creates a digest from a slice, returning an error if the length of the
+    /// slice is not 32 bytes. Use [`Digest::try_from_ref`] for borrowing a
+    /// digest from a slice without copying it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let buffer = [0xee; 33];
+    /// assert_eq!(Digest::try_from_slice(&buffer[..32]).unwrap(), Digest([0xee; 32]));
+    /// assert!(Digest::try_from_slice(&buffer).is_err());
+    /// ```
+    pub fn try_from_slice(slice: &[u8]) -> Result<Self, TryFromSliceError> {
+        slice.try_into()
+    }
+
+    /// Creates a digest from an iterator of bytes, returning an error if the
+    /// iterator does not yield exactly 32 bytes. This is the fallible
+    /// counterpart to collecting into a digest with [`FromIterator`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, FromIterError};
+    /// assert_eq!(
+    ///     Digest::try_from_iter((0..64).step_by(2)).unwrap(),
+    ///     Digest::from_slice(&(0..64).step_by(2).collect::<Vec<_>>()),
+    /// );
+    /// assert_eq!(
+    ///     Digest::try_from_iter([0xee; 31]),
+    ///     Err(FromIterError::TooShort { len: 31 }),
+    /// );
+    /// assert_eq!(
+    ///     Digest::try_from_iter(std::iter::repeat(0xee)),
+    ///     Err(FromIterError::TooLong),
+    /// );
+    /// ```
+    pub fn try_from_iter(iter: impl IntoIterator<Item = u8>) -> Result<Self, FromIterError> {
+        let mut iter = iter.into_iter();
+        let mut digest = Self::default();
+        for (len, byte) in digest.iter_mut().enumerate() {
+            *byte = iter.next().ok_or(FromIterError::TooShort { len })?;
+        }
+        if iter.next().is_some() {
+            return Err(FromIterError::TooLong);
+        }
+        Ok(digest)
+    }
+
+    /// Creates a digest from an iterator of bytes, returning an error if the
+    /// iterator does not yield exactly 32 bytes. This is the same as
+    /// [`Digest::try_from_iter`].
+    pub fn from_iter_exact(iter: impl IntoIterator<Item = u8>) -> Result<Self, FromIterError> {
+        Self::try_from_iter(iter)
+    }
+
+    /// Returns whether or not the digest is the all-zero digest.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert!(Digest::ZERO.is_zero());
+    /// assert!(!Digest::MAX.is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    /// Returns the digest with the byte at the specified index set to a value.
+    /// This is useful for constructing targeted digests in tests.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::ZERO.with_byte(31, 0x01),
+    ///     "0x0000000000000000000000000000000000000000000000000000000000000001",
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_byte(mut self, index: usize, value: u8) -> Self {
+        self.0[index] = value;
+        self
+    }
+
+    /// Returns the digest with its leading `len` bits set to the lowest `len`
+    /// bits of `bits`, most significant bit first. The remaining bits of the
+    /// digest are left unchanged. This is useful for constructing digests
+    /// with specific prefixes for trie and routing table tests.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `len` is greater than 64.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::ZERO.with_prefix_bits(0b101, 3),
+    ///     "0xa000000000000000000000000000000000000000000000000000000000000000",
+    /// );
+    /// assert_eq!(
+    ///     Digest([0xff; 32]).with_prefix_bits(0x0, 12),
+    ///     "0x000fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn with_prefix_bits(mut self, bits: u64, len: u32) -> Self {
+        assert!(len <= 64, "prefix length must be at most 64 bits");
+        let mut i = 0;
+        while i < len {
+            let (byte, mask) = ((i / 8) as usize, 0x80 >> (i % 8));
+            if (bits >> (len - 1 - i)) & 1 == 1 {
+                self.0[byte] |= mask;
+            } else {
+                self.0[byte] &= !mask;
+            }
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns whether or not a string is a valid hex digest, with an
+    /// optional `0x` prefix. This is a cheaper check than parsing, useful for
+    /// quickly filtering large sets of candidate strings.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert!(Digest::is_valid_str(
+    ///     "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+    /// ));
+    /// assert!(Digest::is_valid_str(
+    ///     "EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE",
+    /// ));
+    /// assert!(!Digest::is_valid_str("0xeeee"));
+    /// assert!(!Digest::is_valid_str(
+    ///     "0xgggggggggggggggggggggggggggggggggggggggggggggggggggggggggggggggg",
+    /// ));
+    /// ```
+    pub fn is_valid_str(s: &str) -> bool {
+        hex::is_valid(s.as_bytes())
+    }
+
+    /// Parses a digest from a hex string with an explicit policy for the `0x`
+    /// prefix. Note that the [`FromStr`] implementation always accepts an
+    /// optional prefix.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, ParseDigestError, PrefixPolicy};
+    /// let prefixed = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
+    /// assert_eq!(
+    ///     Digest::from_str_with(prefixed, PrefixPolicy::Required),
+    ///     Ok(Digest([0xee; 32])),
+    /// );
+    /// assert_eq!(
+    ///     Digest::from_str_with(prefixed, PrefixPolicy::Forbidden),
+    ///     Err(ParseDigestError::InvalidPrefix {
+    ///         policy: PrefixPolicy::Forbidden,
+    ///     }),
+    /// );
+    /// assert_eq!(
+    ///     Digest::from_str_with(&prefixed[2..], PrefixPolicy::Required),
+    ///     Err(ParseDigestError::InvalidPrefix {
+    ///         policy: PrefixPolicy::Required,
+    ///     }),
+    /// );
+    /// ```
+    pub fn from_str_with(s: &str, prefix: PrefixPolicy) -> Result<Self, ParseDigestError> {
+        hex::decode_with(s, prefix).map(Self)
+    }
+
+    /// Parses a digest from a hex string, leniently accepting surrounding
+    /// whitespace, an optional `0x` or `0X` prefix and `_` separators. The
+    /// length and characters of the hex string are still validated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// for s in [
+    ///     "  0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee\n",
+    ///     "0XEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE",
+    ///     "eeeeeeee_eeeeeeee_eeeeeeee_eeeeeeee_eeeeeeee_eeeeeeee_eeeeeeee_eeeeeeee",
+    /// ] {
+    ///     assert_eq!(Digest::from_str_lenient(s).unwrap(), Digest([0xee; 32]));
+    /// }
+    /// assert!(Digest::from_str_lenient("0xee ee").is_err());
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Result<Self, ParseDigestError> {
+        hex::decode_lenient(s).map(Self)
+    }
+
+    /// Parses a digest from ASCII hex bytes, with an optional `0x` prefix.
+    /// This avoids having to validate that the input is UTF-8 when parsing
+    /// digests from network buffers or files.
+    ///
+    /// Invalid non-ASCII bytes are reported as the `U+FFFD` replacement
+    /// character in [`ParseDigestError::InvalidHexCharacter`] errors.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Digest, ParseDigestError};
+    /// let mut hex = [b'e'; 66];
+    /// hex[..2].copy_from_slice(b"0x");
+    /// assert_eq!(Digest::from_hex_bytes(&hex), Ok(Digest([0xee; 32])));
+    ///
+    /// hex[65] = 0xff;
+    /// assert_eq!(
+    ///     Digest::from_hex_bytes(&hex),
+    ///     Err(ParseDigestError::InvalidHexCharacter {
+    ///         c: char::REPLACEMENT_CHARACTER,
+    ///         index: 65,
+    ///     }),
+    /// );
+    /// ```
+    pub fn from_hex_bytes(s: &[u8]) -> Result<Self, ParseDigestError> {
+        hex::decode_bytes(s).map(Self)
+    }
+
+    /// Creates a reference to a digest from a reference to a 32-byte array.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let arrays = [[0; 32], [1; 32]];
+    /// for digest in arrays.iter().map(Digest::from_ref) {
+    ///     println!("{digest}");
+    /// }
+    /// ```
+    pub fn from_ref(array: &[u8; 32]) -> &'_ Self {
+        // SAFETY: `Digest` and `[u8; 32]` have the same memory layout.
+        unsafe { &*(array as *const [u8; 32]).cast::<Self>() }
+    }
+
+    /// Creates a mutable reference to a digest from a mutable reference to a
+    /// 32-byte array.
+    pub fn from_mut(array: &mut [u8; 32]) -> &'_ mut Self {
+        // SAFETY: `Digest` and `[u8; 32]` have the same memory layout.
+        unsafe { &mut *(array as *mut [u8; 32]).cast::<Self>() }
+    }
+
+    /// Creates a reference to a digest from a slice, returning an error if the
+    /// length of the slice is not 32 bytes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let buffer = [0xee; 64];
+    /// assert_eq!(Digest::try_from_ref(&buffer[32..]).unwrap(), &Digest([0xee; 32]));
+    /// assert!(Digest::try_from_ref(&buffer[31..]).is_err());
+    /// ```
+    pub fn try_from_ref(slice: &[u8]) -> Result<&'_ Self, TryFromSliceError> {
+        slice.try_into()
+    }
+
+    /// Creates a mutable reference to a digest from a mutable slice, returning
+    /// an error if the length of the slice is not 32 bytes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let mut buffer = [0; 64];
+    /// Digest::try_from_mut(&mut buffer[32..]).unwrap().0 = [0xee; 32];
+    /// assert_eq!(buffer[32..], [0xee; 32]);
+    /// ```
+    pub fn try_from_mut(slice: &mut [u8]) -> Result<&'_ mut Self, TryFromSliceError> {
+        slice.try_into()
+    }
+
+    /// Consumes the digest, returning the underlying 32-byte array.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest([0xee; 32]).into_inner(), [0xee; 32]);
+    /// ```
+    pub fn into_inner(self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Returns a copy of the digest as a 32-byte array.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest([0xee; 32]);
+    /// assert_eq!(digest.to_array(), [0xee; 32]);
+    /// ```
+    pub fn to_array(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Creates a digest from its little-endian byte representation.
+    ///
+    /// Digests are canonically big-endian, matching the order in which they
+    /// are displayed and used in Ethereum. This reverses the byte order of
+    /// words stored as little-endian, as is common in zk proving systems and
+    /// some databases.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let mut bytes = [0; 32];
+    /// bytes[0] = 1;
+    /// let digest = Digest::from_le_bytes(bytes);
+    /// assert_eq!(digest[31], 1);
+    /// assert_eq!(digest.to_le_bytes(), bytes);
+    /// ```
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Self {
+        Self(bytes).swap_bytes()
+    }
+
+    /// Returns the little-endian byte representation of the digest. See
+    /// [`Digest::from_le_bytes`] for more details.
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        self.swap_bytes().0
+    }
+
+    /// Returns the digest with the order of its bytes reversed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest::from_slice(&(0..32).collect::<Vec<_>>());
+    /// assert_eq!(
+    ///     digest.swap_bytes(),
+    ///     Digest::from_slice(&(0..32).rev().collect::<Vec<_>>()),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn swap_bytes(&self) -> Self {
+        let mut digest = *self;
+        digest.0.reverse();
+        digest
+    }
+
+    /// Returns an iterator over the 64 nibbles (half-bytes) of the digest,
+    /// most significant first. This is the natural unit for traversing Merkle
+    /// Patricia tries. See [`Digest::nibble_path`] for a nibble path type with
+    /// prefix helpers.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest::ZERO.with_byte(0, 0xab).with_byte(31, 0xcd);
+    /// let nibbles = digest.nibbles().collect::<Vec<_>>();
+    /// assert_eq!(nibbles.len(), 64);
+    /// assert_eq!(nibbles[..3], [0xa, 0xb, 0x0]);
+    /// assert_eq!(nibbles[62..], [0xc, 0xd]);
+    /// ```
+    pub fn nibbles(&self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator {
+        let bytes = self.0;
+        (0..64).map(move |i| (bytes[i / 2] >> (4 * (1 - i % 2))) & 0xf)
+    }
+
+    /// Returns the number of ones in the binary representation of the digest.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::ZERO.count_ones(), 0);
+    /// assert_eq!(Digest([0xee; 32]).count_ones(), 192);
+    /// ```
+    pub fn count_ones(&self) -> u32 {
+        self.0
+            .chunks_exact(8)
+            .map(|limb| u64::from_ne_bytes(limb.try_into().unwrap()).count_ones())
+            .sum()
+    }
+
+    /// Returns the number of zeros in the binary representation of the digest.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::ZERO.count_zeros(), 256);
+    /// assert_eq!(Digest([0xee; 32]).count_zeros(), 64);
+    /// ```
+    pub fn count_zeros(&self) -> u32 {
+        256 - self.count_ones()
+    }
+
+    /// Returns the first 4 bytes of the digest, as used for Solidity function
+    /// selectors.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = "0xa9059cbb2ab09eb219583f4a59a5d0623ade346d962bcd4e46b11da047c9049b"
+    ///     .parse::<Digest>()
+    ///     .unwrap();
+    /// assert_eq!(digest.to_selector(), [0xa9, 0x05, 0x9c, 0xbb]);
+    /// ```
+    pub fn to_selector(&self) -> [u8; 4] {
+        self.0[..4].try_into().unwrap()
+    }
+
+    /// Returns the last 20 bytes of the digest, as used for deriving Ethereum
+    /// addresses from public key hashes. The first 12 bytes of the digest are
+    /// discarded.
     ///
     /// # Examples
     ///
@@ -100,22 +867,18 @@ impl Digest {
     ///
     /// ```
     /// # use ethdigest::Digest;
-    /// let buffer = (0..255).collect::<Vec<_>>();
-    /// assert_eq!(
-    ///     Digest::from_slice(&buffer[0..32]),
-    ///     Digest([
-    ///         0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
-    ///         0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
-    ///         0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
-    ///         0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
-    ///     ]),
-    /// );
+    /// let mut digest = Digest([0xee; 32]);
+    /// digest[..12].fill(0);
+    /// assert_eq!(digest.to_address_lossy(), [0xee; 20]);
     /// ```
-    pub fn from_slice(slice: &[u8]) -> Self {
-        slice.try_into().unwrap()
+    #[doc(alias = "to_address_bytes")]
+    pub fn to_address_lossy(&self) -> [u8; 20] {
+        self.0[12..].try_into().unwrap()
     }
 
-    /// Creates a reference to a digest from a reference to a 32-byte array.
+    /// Creates a digest from a 20-byte address, left-padded with 12 zero
+    /// bytes. This is how addresses are encoded as 32-byte words, for example
+    /// in indexed event topics and storage keys.
     ///
     /// # Examples
     ///
@@ -123,21 +886,18 @@ impl Digest {
     ///
     /// ```
     /// # use ethdigest::Digest;
-    /// let arrays = [[0; 32], [1; 32]];
-    /// for digest in arrays.iter().map(Digest::from_ref) {
-    ///     println!("{digest}");
-    /// }
+    /// let digest = Digest::from_address_bytes([0xee; 20]);
+    /// assert_eq!(digest[..12], [0; 12]);
+    /// assert_eq!(digest.to_address_lossy(), [0xee; 20]);
     /// ```
-    pub fn from_ref(array: &[u8; 32]) -> &'_ Self {
-        // SAFETY: `Digest` and `[u8; 32]` have the same memory layout.
-        unsafe { &*(array as *const [u8; 32]).cast::<Self>() }
-    }
-
-    /// Creates a mutable reference to a digest from a mutable reference to a
-    /// 32-byte array.
-    pub fn from_mut(array: &mut [u8; 32]) -> &'_ mut Self {
-        // SAFETY: `Digest` and `[u8; 32]` have the same memory layout.
-        unsafe { &mut *(array as *mut [u8; 32]).cast::<Self>() }
+    pub const fn from_address_bytes(address: [u8; 20]) -> Self {
+        let mut digest = [0; 32];
+        let mut i = 0;
+        while i < 20 {
+            digest[12 + i] = address[i];
+            i += 1;
+        }
+        Self(digest)
     }
 
     /// Creates a digest by hashing some input.
@@ -164,6 +924,122 @@ impl Digest {
         hasher.update(data);
         hasher.finalize()
     }
+
+    /// Creates a digest by hashing some input in a `const` context.
+    ///
+    /// This is slower than [`Digest::of`] at runtime, so prefer that for
+    /// non-`const` hashing.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// const TRANSFER: Digest = Digest::of_const(b"Transfer(address,address,uint256)");
+    /// assert_eq!(TRANSFER, Digest::of("Transfer(address,address,uint256)"));
+    /// ```
+    #[cfg(feature = "keccak")]
+    pub const fn of_const(data: &[u8]) -> Self {
+        Self(keccakf::keccak256(data))
+    }
+
+    /// Returns a display adapter that formats the digest as a `0x`-prefixed
+    /// hex string with a `_` separator every `size` bytes. This is useful for
+    /// visually comparing similar digests.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest([0xee; 32]);
+    /// assert_eq!(
+    ///     digest.grouped(8).to_string(),
+    ///     "0xeeeeeeeeeeeeeeee_eeeeeeeeeeeeeeee_eeeeeeeeeeeeeeee_eeeeeeeeeeeeeeee",
+    /// );
+    /// ```
+    pub fn grouped(&self, size: usize) -> Grouped<'_> {
+        assert!(size > 0, "group size must be non-zero");
+        Grouped { digest: self, size }
+    }
+
+    /// Returns a display adapter that formats an abbreviated digest, keeping
+    /// only the first and last few hex digits. This is useful for keeping log
+    /// lines readable.
+    ///
+    /// By default, 8 hex digits are kept. This can be changed with the
+    /// formatting precision, which is also supported by the [`Display`]
+    /// implementation of [`Digest`] itself.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest([0xee; 32]);
+    /// assert_eq!(digest.short().to_string(), "0xeeee…eeee");
+    /// assert_eq!(format!("{:.4}", digest.short()), "0xee…ee");
+    /// assert_eq!(format!("{digest:.6}"), "0xeee…eee");
+    /// ```
+    pub fn short(&self) -> Short<'_> {
+        Short { digest: self }
+    }
+
+    /// Formats the digest as a hex string directly into a caller-provided
+    /// buffer, returning the formatted string. This is useful for encoding
+    /// many digests into preallocated memory.
+    ///
+    /// The digest is written to the start of the buffer, optionally with a
+    /// `0x` prefix and using either lowercase or uppercase hex digits.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the buffer is shorter than 64 bytes, or 66 bytes
+    /// when including the `0x` prefix.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let mut buffer = [0; 66];
+    /// let digest = Digest([0xee; 32]);
+    /// assert_eq!(
+    ///     digest.write_hex(&mut buffer, true, false),
+    ///     "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+    /// );
+    /// assert_eq!(
+    ///     digest.write_hex(&mut buffer, false, true),
+    ///     "EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE",
+    /// );
+    /// ```
+    pub fn write_hex<'a>(&self, buf: &'a mut [u8], prefix: bool, upper: bool) -> &'a str {
+        let start = if prefix { 2 } else { 0 };
+        assert!(buf.len() >= start + 64, "buffer too short for digest");
+
+        buf[..start].copy_from_slice(&b"0x"[..start]);
+        let alphabet = if upper {
+            Alphabet::Upper
+        } else {
+            Alphabet::Lower
+        };
+        buffer::encode(
+            self,
+            alphabet,
+            (&mut buf[start..start + 64]).try_into().unwrap(),
+        );
+
+        // SAFETY: The buffer was just filled with ASCII characters.
+        unsafe { str::from_utf8_unchecked(&buf[..start + 64]) }
+    }
 }
 
 impl Debug for Digest {
@@ -176,7 +1052,10 @@ impl Debug for Digest {
 
 impl Display for Digest {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.pad(buffer::fmt(self, Alphabet::default()).as_str())
+        match f.precision() {
+            Some(digits) => display::truncated(self, digits, f),
+            None => f.pad(buffer::fmt(self, Alphabet::default()).as_str()),
+        }
     }
 }
 
@@ -202,6 +1081,41 @@ impl UpperHex for Digest {
     }
 }
 
+impl Binary for Digest {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut buffer = [b'0'; 258];
+        buffer[1] = b'b';
+        for (i, digit) in buffer[2..].iter_mut().enumerate() {
+            *digit += u8::from(self.bit(i));
+        }
+        radix(f, &buffer)
+    }
+}
+
+impl Octal for Digest {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // 256 bits are formatted as 86 octal digits, where the first digit
+        // only holds a single bit.
+        let mut buffer = [b'0'; 88];
+        buffer[1] = b'o';
+        for (i, digit) in buffer[2..].iter_mut().enumerate() {
+            let value = (i * 3..i * 3 + 3)
+                .filter_map(|bit| bit.checked_sub(2))
+                .fold(0, |value, bit| (value << 1) | u8::from(self.bit(bit)));
+            *digit += value;
+        }
+        radix(f, &buffer)
+    }
+}
+
+/// Pads a formatted digest including its radix prefix, which is only kept for
+/// alternate formatting.
+fn radix(f: &mut Formatter, buffer: &[u8]) -> fmt::Result {
+    // SAFETY: The buffer only contains ASCII digits.
+    let s = unsafe { str::from_utf8_unchecked(buffer) };
+    f.pad(if f.alternate() { s } else { &s[2..] })
+}
+
 impl AsRef<[u8; 32]> for Digest {
     fn as_ref(&self) -> &[u8; 32] {
         &self.0
@@ -226,6 +1140,18 @@ impl AsMut<[u8]> for Digest {
     }
 }
 
+impl Borrow<[u8; 32]> for Digest {
+    fn borrow(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl Borrow<[u8]> for Digest {
+    fn borrow(&self) -> &[u8] {
+        &self.0
+    }
+}
+
 impl Deref for Digest {
     type Target = [u8; 32];
 
@@ -240,6 +1166,49 @@ impl DerefMut for Digest {
     }
 }
 
+impl From<[u8; 32]> for Digest {
+    fn from(value: [u8; 32]) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&'_ [u8; 32]> for Digest {
+    fn from(value: &'_ [u8; 32]) -> Self {
+        Self(*value)
+    }
+}
+
+impl From<Digest> for [u8; 32] {
+    fn from(value: Digest) -> Self {
+        value.0
+    }
+}
+
+/// Collects exactly 32 bytes into a digest.
+///
+/// # Panics
+///
+/// Collecting panics if the iterator does not yield exactly 32 bytes. Use
+/// [`Digest::try_from_iter`] for a non-panicking alternative.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Digest;
+/// let digest = (0..32).map(|i| i * 2).collect::<Digest>();
+/// assert_eq!(digest[31], 62);
+/// ```
+impl FromIterator<u8> for Digest {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        match Self::try_from_iter(iter) {
+            Ok(digest) => digest,
+            Err(err) => panic!("{err}"),
+        }
+    }
+}
+
 impl FromStr for Digest {
     type Err = ParseDigestError;
 
@@ -290,13 +1259,55 @@ impl PartialEq<&'_ mut [u8]> for Digest {
     }
 }
 
-#[cfg(feature = "std")]
+impl PartialEq<str> for Digest {
+    fn eq(&self, other: &'_ str) -> bool {
+        matches!(hex::decode_bytes(other.as_bytes()), Ok(bytes) if bytes == self.0)
+    }
+}
+
+impl PartialEq<&'_ str> for Digest {
+    fn eq(&self, other: &&'_ str) -> bool {
+        *self == **other
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl PartialEq<Vec<u8>> for Digest {
     fn eq(&self, other: &Vec<u8>) -> bool {
         **self == **other
     }
 }
 
+impl PartialEq<Digest> for str {
+    fn eq(&self, other: &Digest) -> bool {
+        *other == *self
+    }
+}
+
+impl PartialEq<Digest> for &'_ str {
+    fn eq(&self, other: &Digest) -> bool {
+        *other == **self
+    }
+}
+
+impl PartialOrd<[u8; 32]> for Digest {
+    fn partial_cmp(&self, other: &'_ [u8; 32]) -> Option<Ordering> {
+        (**self).partial_cmp(other)
+    }
+}
+
+impl PartialOrd<[u8]> for Digest {
+    fn partial_cmp(&self, other: &'_ [u8]) -> Option<Ordering> {
+        (**self)[..].partial_cmp(other)
+    }
+}
+
+impl PartialOrd<&'_ [u8]> for Digest {
+    fn partial_cmp(&self, other: &&'_ [u8]) -> Option<Ordering> {
+        (**self)[..].partial_cmp(*other)
+    }
+}
+
 impl TryFrom<&'_ [u8]> for Digest {
     type Error = TryFromSliceError;
 
@@ -329,7 +1340,7 @@ impl<'a> TryFrom<&'a mut [u8]> for &'a mut Digest {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl TryFrom<Vec<u8>> for Digest {
     type Error = Vec<u8>;
 
@@ -338,6 +1349,30 @@ impl TryFrom<Vec<u8>> for Digest {
     }
 }
 
+/// Represents an error creating a digest from an iterator that does not yield
+/// exactly 32 bytes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FromIterError {
+    /// The iterator yielded fewer than 32 bytes.
+    TooShort {
+        /// The number of bytes the iterator yielded.
+        len: usize,
+    },
+    /// The iterator yielded more than 32 bytes.
+    TooLong,
+}
+
+impl Display for FromIterError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::TooShort { len } => write!(f, "iterator yielded {len} bytes, expected 32"),
+            Self::TooLong => write!(f, "iterator yielded more than 32 bytes"),
+        }
+    }
+}
+
+impl core::error::Error for FromIterError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -370,4 +1405,282 @@ mod tests {
             "0xEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE"
         );
     }
+
+    #[test]
+    fn lenient_parsing_errors() {
+        assert_eq!(
+            Digest::from_str_lenient(" 0xeeeeeeee_eeeeeeeg"),
+            Err(ParseDigestError::InvalidHexCharacter { c: 'g', index: 19 }),
+        );
+        assert_eq!(
+            Digest::from_str_lenient("0xeeeeeeee_eeeeeeee"),
+            Err(ParseDigestError::InvalidLength {
+                len: 19,
+                expected: 67,
+            }),
+        );
+        assert_eq!(
+            Digest::from_str_lenient(&"e".repeat(65)),
+            Err(ParseDigestError::InvalidLength {
+                len: 65,
+                expected: 64,
+            }),
+        );
+    }
+
+    #[test]
+    fn parsing_reports_lengths() {
+        for (input, len, expected) in [
+            ("", 0, 64),
+            ("0x", 2, 66),
+            ("0xeeee", 6, 66),
+            ("eeee", 4, 64),
+        ] {
+            let err = input.parse::<Digest>().unwrap_err();
+            assert_eq!(err, ParseDigestError::InvalidLength { len, expected });
+            assert_eq!(err.span(), 0..len);
+        }
+    }
+
+    #[test]
+    fn invalid_length_reports_first_invalid_character() {
+        let digest = format!("0xg{}  ", "e".repeat(63));
+        assert_eq!(
+            digest.parse::<Digest>(),
+            Err(ParseDigestError::InvalidHexCharacter { c: 'g', index: 2 }),
+        );
+
+        let digest = format!("0xé{}  ", "e".repeat(63));
+        assert_eq!(
+            digest.parse::<Digest>(),
+            Err(ParseDigestError::InvalidHexCharacter { c: 'é', index: 2 }),
+        );
+    }
+
+    #[test]
+    fn parsing_suggestions() {
+        let digest = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
+        for (input, suggestion) in [
+            (&digest[..65], Suggestion::MissingDigit),
+            (&format!("{digest}e"), Suggestion::ExtraDigit),
+            (&format!("{digest}\n"), Suggestion::RemoveWhitespace),
+            (&format!(" {digest}"), Suggestion::RemoveWhitespace),
+            (
+                &digest.replace("0x", "0X"),
+                Suggestion::ReplaceCharacter {
+                    found: 'X',
+                    replacement: 'x',
+                },
+            ),
+            (
+                &digest.replacen('e', "O", 1),
+                Suggestion::ReplaceCharacter {
+                    found: 'O',
+                    replacement: '0',
+                },
+            ),
+            (
+                &digest.replacen('e', "l", 1),
+                Suggestion::ReplaceCharacter {
+                    found: 'l',
+                    replacement: '1',
+                },
+            ),
+        ] {
+            let err = input.parse::<Digest>().unwrap_err();
+            assert_eq!(err.suggestion(), Some(suggestion));
+        }
+
+        let err = digest[..64].parse::<Digest>().unwrap_err();
+        assert_eq!(err.suggestion(), None);
+        assert_eq!(
+            digest
+                .replacen('e', "O", 1)
+                .parse::<Digest>()
+                .unwrap_err()
+                .to_string(),
+            "invalid character `O` at position 2 (replace `O` with `0`)",
+        );
+    }
+
+    #[test]
+    fn parsing_reports_invalid_characters() {
+        assert_eq!(
+            format!("0x{}é", "e".repeat(62)).parse::<Digest>(),
+            Err(ParseDigestError::InvalidHexCharacter { c: 'é', index: 64 }),
+        );
+        assert_eq!(
+            Digest::from_hex_bytes(&[b'e'; 64].map(|c| c ^ 0x80)),
+            Err(ParseDigestError::InvalidHexCharacter {
+                c: char::REPLACEMENT_CHARACTER,
+                index: 0,
+            }),
+        );
+    }
+
+    #[test]
+    fn hex_string_equality() {
+        let digest = Digest([0xee; 32]);
+        assert_eq!(
+            digest,
+            "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee"
+        );
+        assert_eq!(
+            digest,
+            "EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE"
+        );
+        assert_ne!(
+            digest,
+            "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeef"
+        );
+        assert_ne!(digest, "0xeeee");
+        assert_ne!(digest, "not a digest");
+        assert_eq!(
+            "0xEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE",
+            digest
+        );
+    }
+
+    #[test]
+    fn byte_ordering() {
+        let digest = Digest([0xee; 32]);
+        assert!(digest < [0xff; 32]);
+        assert!(digest > [0xee; 31][..]);
+        let key: &[u8] = &[0xef];
+        assert!(digest < key);
+        assert_eq!(digest.partial_cmp(&[0xee; 32]), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn borrowed_map_lookups() {
+        use std::collections::{BTreeMap, HashMap};
+
+        let digest = Digest([0xee; 32]);
+        let hash_map = HashMap::from([(digest, 42)]);
+        let btree_map = BTreeMap::from([(digest, 42)]);
+
+        assert_eq!(hash_map.get(&[0xee; 32]), Some(&42));
+        assert_eq!(hash_map.get(&[0xee; 32][..]), Some(&42));
+        assert_eq!(btree_map.get(&[0xee; 32]), Some(&42));
+        assert_eq!(btree_map.get(&[0xee; 32][..]), Some(&42));
+        assert_eq!(hash_map.get(&[0xee; 31][..]), None);
+    }
+
+    #[test]
+    fn array_conversions() {
+        fn roundtrip(array: impl Into<Digest>) -> [u8; 32] {
+            array.into().into()
+        }
+
+        let array = [0xee; 32];
+        assert_eq!(roundtrip(array), array);
+        assert_eq!(Digest::from(&array), Digest(array));
+    }
+
+    #[test]
+    fn const_hex_parsing() {
+        const DIGESTS: [Digest; 3] = [
+            Digest::from_hex("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee"),
+            Digest::from_hex("EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE"),
+            Digest::from_hex("0xeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeEeE"),
+        ];
+        assert_eq!(DIGESTS, [Digest([0xee; 32]); 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_hex_parsing_invalid_digit() {
+        Digest::from_hex("0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeg");
+    }
+
+    #[test]
+    fn truncated_formatting() {
+        let digest = Digest([
+            0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe,
+            0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef,
+            0xca, 0xfe, 0xba, 0xbe,
+        ]);
+        assert_eq!(format!("{digest:.0}"), "0x…");
+        assert_eq!(format!("{digest:.5}"), "0xdea…be");
+        assert_eq!(format!("{digest:.8}"), "0xdead…babe");
+        assert_eq!(
+            format!("{digest:.64}"),
+            "0xdeadbeefcafebabedeadbeefcafebabedeadbeefcafebabedeadbeefcafebabe"
+        );
+        assert_eq!(format!("{digest:>14.8}"), "   0xdead…babe");
+        assert_eq!(format!("{digest:-^15.8}"), "--0xdead…babe--");
+        assert_eq!(format!("{:<13}|", digest.short()), "0xdead…babe  |");
+        assert_eq!(format!("{:.2}", digest.short()), "0xd…e");
+    }
+
+    #[test]
+    fn binary_and_octal_formatting() {
+        let digest = Digest::ZERO.with_byte(0, 0x80).with_byte(31, 0x05);
+
+        let binary = format!("{digest:b}");
+        assert_eq!(binary.len(), 256);
+        assert!(binary.starts_with("10000000"));
+        assert!(binary.ends_with("00000101"));
+        assert_eq!(format!("{digest:#b}"), format!("0b{binary}"));
+
+        let octal = format!("{digest:o}");
+        assert_eq!(octal.len(), 86);
+        assert!(octal.starts_with("10"));
+        assert!(octal.ends_with("005"));
+        assert_eq!(
+            format!("{:#o}", Digest::MAX),
+            format!("0o1{}", "7".repeat(85))
+        );
+        assert_eq!(
+            format!("{:>90o}|", Digest::ZERO),
+            format!("    {}|", "0".repeat(86))
+        );
+    }
+
+    #[test]
+    fn write_hex_into_buffer() {
+        let digest = Digest([
+            0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe,
+            0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef,
+            0xca, 0xfe, 0xba, 0xbe,
+        ]);
+        let mut buffer = [b'_'; 70];
+        for (prefix, upper) in [(false, false), (false, true), (true, false), (true, true)] {
+            let expected = match (prefix, upper) {
+                (false, false) => format!("{digest:x}"),
+                (false, true) => format!("{digest:X}"),
+                (true, false) => format!("{digest:#x}"),
+                (true, true) => format!("0x{digest:X}"),
+            };
+            assert_eq!(digest.write_hex(&mut buffer, prefix, upper), expected);
+        }
+        assert_eq!(&buffer[66..], b"____");
+    }
+
+    #[test]
+    #[should_panic]
+    fn write_hex_buffer_too_short() {
+        Digest::ZERO.write_hex(&mut [0; 65], true, false);
+    }
+
+    #[test]
+    fn grouped_formatting() {
+        let digest = Digest([
+            0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe,
+            0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef, 0xca, 0xfe, 0xba, 0xbe, 0xde, 0xad, 0xbe, 0xef,
+            0xca, 0xfe, 0xba, 0xbe,
+        ]);
+        assert_eq!(
+            digest.grouped(4).to_string(),
+            "0xdeadbeef_cafebabe_deadbeef_cafebabe_deadbeef_cafebabe_deadbeef_cafebabe"
+        );
+        assert_eq!(
+            digest.grouped(5).to_string(),
+            "0xdeadbeefca_febabedead_beefcafeba_bedeadbeef_cafebabede_adbeefcafe_babe"
+        );
+        assert_eq!(
+            digest.grouped(32).to_string(),
+            "0xdeadbeefcafebabedeadbeefcafebabedeadbeefcafebabedeadbeefcafebabe"
+        );
+    }
 }
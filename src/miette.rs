@@ -0,0 +1,49 @@
+//! Diagnostic implementations for the [`miette`] crate.
+
+use crate::ParseDigestError;
+use miette::{Diagnostic, LabeledSpan};
+use std::{fmt::Display, iter};
+
+impl Diagnostic for ParseDigestError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(match self {
+            Self::InvalidLength { .. } => "ethdigest::invalid_length",
+            Self::InvalidHexCharacter { .. } => "ethdigest::invalid_hex_character",
+            Self::InvalidPrefix { .. } => "ethdigest::invalid_prefix",
+        }))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        let suggestion = self.suggestion()?;
+        Some(Box::new(suggestion))
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = match self {
+            Self::InvalidLength { expected, .. } => format!("expected {expected} characters"),
+            Self::InvalidHexCharacter { .. } => "not a hex digit".to_owned(),
+            Self::InvalidPrefix { .. } => self.to_string(),
+        };
+        Some(Box::new(iter::once(LabeledSpan::new_with_span(
+            Some(label),
+            self.span(),
+        ))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Digest;
+
+    #[test]
+    fn labels_point_at_invalid_character() {
+        let err = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeg"
+            .parse::<Digest>()
+            .unwrap_err();
+        let labels = err.labels().unwrap().collect::<Vec<_>>();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), 65);
+        assert_eq!(labels[0].len(), 1);
+    }
+}
@@ -0,0 +1,75 @@
+//! Module implementing hashing of memory-mapped files.
+
+use crate::{Digest, Keccak};
+use memmap2::Mmap;
+use std::{fs::File, io, path::Path};
+
+impl Digest {
+    /// Creates a digest by memory mapping a file and hashing its contents,
+    /// returning it along with the number of bytes that were hashed. This
+    /// avoids copying file contents through an intermediate buffer, which can
+    /// be significantly faster than [`Digest::of_file`] for very large files.
+    ///
+    /// Note that Keccak-256 is inherently sequential, so the file is hashed on
+    /// a single thread.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the file is not modified (by this or any
+    /// other process) while it is being hashed, as this is undefined
+    /// behaviour for memory-mapped files.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let path = std::env::temp_dir().join("ethdigest-of-mmap.txt");
+    /// std::fs::write(&path, "Hello Ethereum!").unwrap();
+    /// // SAFETY: The file is not modified while it is being hashed.
+    /// assert_eq!(
+    ///     unsafe { Digest::of_mmap(&path) }.unwrap(),
+    ///     (Digest::of("Hello Ethereum!"), 15),
+    /// );
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub unsafe fn of_mmap(path: impl AsRef<Path>) -> io::Result<(Self, u64)> {
+        let file = File::open(path)?;
+
+        // Empty files cannot be mapped on all platforms.
+        if file.metadata()?.len() == 0 {
+            return Ok((Digest::of([]), 0));
+        }
+
+        // SAFETY: The caller guarantees that the file is not modified while
+        // it is mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+        #[cfg(unix)]
+        mmap.advise(memmap2::Advice::Sequential)?;
+
+        let mut hasher = Keccak::new();
+        hasher.update(&mmap[..]);
+        Ok((hasher.finalize(), mmap.len() as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+
+    #[test]
+    fn mmap_matches_buffered() {
+        let path = env::temp_dir().join("ethdigest-mmap-matches-buffered.bin");
+        for len in [0, 1, 135, 136, 137, 200_000] {
+            let data = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+            fs::write(&path, &data).unwrap();
+            assert_eq!(
+                unsafe { Digest::of_mmap(&path) }.unwrap(),
+                Digest::of_file(&path).unwrap(),
+            );
+        }
+        fs::remove_file(&path).unwrap();
+    }
+}
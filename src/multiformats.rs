@@ -0,0 +1,431 @@
+//! Module implementing base58, multihash and CID encodings for Ethereum 32-byte
+//! digests.
+//!
+//! Digests are assumed to be Keccak-256 hashes, and are encoded as multihashes
+//! using the `keccak-256` multicodec (`0x1b`).
+
+use crate::{base58, Digest};
+use core::{
+    fmt::{self, Debug, Display, Formatter},
+    ops::Deref,
+    str::FromStr,
+};
+
+/// The `keccak-256` multihash code.
+const KECCAK_256: u8 = 0x1b;
+
+/// The length of a Keccak-256 multihash.
+const MULTIHASH_LEN: usize = 34;
+
+/// The maximum length of an encoded CIDv1 with a Keccak-256 multihash. This
+/// includes a 1-byte version, a codec varint of up to 9 bytes and the
+/// multihash.
+const CID_MAX_LEN: usize = 1 + 9 + MULTIHASH_LEN;
+
+impl Digest {
+    /// Encodes the digest as a base58 string using the Bitcoin alphabet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest([0xee; 32]);
+    /// assert_eq!(
+    ///     digest.to_base58().as_str(),
+    ///     "H5hM4fqRjygvCYXnp6dgFLgZ6o4uJ8Q9z7dAsTfapHmF",
+    /// );
+    /// ```
+    pub fn to_base58(&self) -> Base58 {
+        Base58(base58::encode(&self.0))
+    }
+
+    /// Parses a digest from a base58 string using the Bitcoin alphabet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::from_base58("H5hM4fqRjygvCYXnp6dgFLgZ6o4uJ8Q9z7dAsTfapHmF").unwrap(),
+    ///     Digest([0xee; 32]),
+    /// );
+    /// ```
+    pub fn from_base58(s: &str) -> Result<Self, ParseMultiformatError> {
+        let buffer = base58::decode(s)?;
+        let bytes = buffer
+            .as_bytes()
+            .try_into()
+            .map_err(|_| ParseMultiformatError::InvalidLength)?;
+        Ok(Self(bytes))
+    }
+
+    /// Returns the Keccak-256 multihash of the digest.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let multihash = Digest([0xee; 32]).to_multihash();
+    /// assert_eq!(multihash[..2], [0x1b, 0x20]);
+    /// assert_eq!(multihash[2..], [0xee; 32]);
+    /// ```
+    pub fn to_multihash(&self) -> [u8; MULTIHASH_LEN] {
+        let mut multihash = [0; MULTIHASH_LEN];
+        multihash[0] = KECCAK_256;
+        multihash[1] = 32;
+        multihash[2..].copy_from_slice(&self.0);
+        multihash
+    }
+
+    /// Creates a digest from a Keccak-256 multihash. Multihashes using other
+    /// hash functions are rejected.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let digest = Digest([0xee; 32]);
+    /// assert_eq!(Digest::from_multihash(&digest.to_multihash()).unwrap(), digest);
+    /// ```
+    pub fn from_multihash(bytes: &[u8]) -> Result<Self, ParseMultiformatError> {
+        match bytes {
+            [KECCAK_256, 32, digest @ ..] => {
+                Digest::try_from(digest).map_err(|_| ParseMultiformatError::InvalidLength)
+            }
+            [_, _, ..] => Err(ParseMultiformatError::UnsupportedMultihash),
+            _ => Err(ParseMultiformatError::InvalidLength),
+        }
+    }
+}
+
+/// A base58-encoded digest.
+pub struct Base58(base58::Buffer);
+
+impl Base58 {
+    /// Returns the base58 string.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl Debug for Base58 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Base58 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// A CIDv1 content identifier for content addressed by its Keccak-256 digest.
+///
+/// CIDs are formatted and parsed as base58btc multibase strings (that is,
+/// base58 strings with a `z` prefix).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Cid, Digest};
+/// let cid = Cid::new(Cid::RAW, Digest([0xee; 32]));
+/// assert_eq!(
+///     cid.to_string(),
+///     "zb34WDijTqU2wxj4Fpk7Tzt442XCU5cGcg1XT9qgvXYhYpm8Z",
+/// );
+/// assert_eq!(
+///     "zb34WDijTqU2wxj4Fpk7Tzt442XCU5cGcg1XT9qgvXYhYpm8Z".parse::<Cid>().unwrap(),
+///     cid,
+/// );
+/// assert_eq!(Digest::from(cid), Digest([0xee; 32]));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Cid {
+    /// The multicodec of the content.
+    codec: u64,
+    /// The Keccak-256 digest of the content.
+    pub digest: Digest,
+}
+
+impl Cid {
+    /// The `raw` multicodec for raw binary content.
+    pub const RAW: u64 = 0x55;
+
+    /// The largest supported multicodec, as multiformats limit varints to 9
+    /// bytes (63 bits).
+    pub const MAX_CODEC: u64 = (1 << 63) - 1;
+
+    /// Creates a new CID for content with the specified multicodec and digest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the multicodec is greater than [`Cid::MAX_CODEC`].
+    pub const fn new(codec: u64, digest: Digest) -> Self {
+        assert!(codec <= Self::MAX_CODEC, "multicodec exceeds 63 bits");
+        Self { codec, digest }
+    }
+
+    /// Returns the multicodec of the content.
+    pub const fn codec(&self) -> u64 {
+        self.codec
+    }
+
+    /// Returns the binary encoding of the CID.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::{Cid, Digest};
+    /// let cid = Cid::new(0x90, Digest([0xee; 32]));
+    /// let bytes = cid.to_bytes();
+    /// assert_eq!(bytes[..5], [0x01, 0x90, 0x01, 0x1b, 0x20]);
+    /// assert_eq!(Cid::from_bytes(&bytes).unwrap(), cid);
+    /// ```
+    pub fn to_bytes(&self) -> CidBytes {
+        let mut bytes = CidBytes {
+            bytes: [0; CID_MAX_LEN],
+            len: 0,
+        };
+        bytes.push(1);
+        let mut codec = self.codec;
+        while codec >= 0x80 {
+            bytes.push((codec as u8) | 0x80);
+            codec >>= 7;
+        }
+        bytes.push(codec as u8);
+        for byte in self.digest.to_multihash() {
+            bytes.push(byte);
+        }
+        bytes
+    }
+
+    /// Decodes a CID from its binary encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseMultiformatError> {
+        let bytes = match bytes {
+            [1, bytes @ ..] => bytes,
+            [_, ..] => return Err(ParseMultiformatError::UnsupportedVersion),
+            [] => return Err(ParseMultiformatError::InvalidLength),
+        };
+
+        let mut codec = 0_u64;
+        let mut len = 0;
+        loop {
+            // Multiformats limit varints to 9 bytes.
+            let byte = *bytes
+                .get(len)
+                .filter(|_| len < 9)
+                .ok_or(ParseMultiformatError::InvalidVarint)?;
+            codec |= ((byte & 0x7f) as u64) << (len * 7);
+            len += 1;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        let digest = Digest::from_multihash(&bytes[len..])?;
+        Ok(Self { codec, digest })
+    }
+}
+
+impl From<Cid> for Digest {
+    fn from(cid: Cid) -> Self {
+        cid.digest
+    }
+}
+
+impl Display for Cid {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("z")?;
+        f.write_str(base58::encode(&self.to_bytes()).as_str())
+    }
+}
+
+impl FromStr for Cid {
+    type Err = ParseMultiformatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s
+            .strip_prefix('z')
+            .ok_or(ParseMultiformatError::UnsupportedMultibase)?;
+        let buffer = base58::decode(s).map_err(|err| match err.into() {
+            ParseMultiformatError::InvalidBase58Character { c, index } => {
+                ParseMultiformatError::InvalidBase58Character {
+                    c,
+                    index: index + 1,
+                }
+            }
+            err => err,
+        })?;
+        Self::from_bytes(buffer.as_bytes())
+    }
+}
+
+/// The binary encoding of a [`Cid`].
+pub struct CidBytes {
+    bytes: [u8; CID_MAX_LEN],
+    len: usize,
+}
+
+impl CidBytes {
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+}
+
+impl AsRef<[u8]> for CidBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Debug for CidBytes {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl Deref for CidBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes[..self.len]
+    }
+}
+
+/// Represents an error parsing a base58, multihash or CID encoded digest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseMultiformatError {
+    /// An invalid base58 character was found.
+    InvalidBase58Character { c: char, index: usize },
+    /// The encoded value does not have the correct length.
+    InvalidLength,
+    /// The CID string uses a multibase other than base58btc.
+    UnsupportedMultibase,
+    /// The CID is not a CIDv1.
+    UnsupportedVersion,
+    /// The multihash is not a Keccak-256 multihash.
+    UnsupportedMultihash,
+    /// The CID contains an invalid varint.
+    InvalidVarint,
+}
+
+impl From<base58::DecodeError> for ParseMultiformatError {
+    fn from(err: base58::DecodeError) -> Self {
+        match err {
+            base58::DecodeError::InvalidCharacter { c, index } => {
+                Self::InvalidBase58Character { c, index }
+            }
+            base58::DecodeError::Overflow => Self::InvalidLength,
+        }
+    }
+}
+
+impl Display for ParseMultiformatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidBase58Character { c, index } => {
+                write!(f, "invalid base58 character `{c}` at position {index}")
+            }
+            Self::InvalidLength => write!(f, "invalid encoded length"),
+            Self::UnsupportedMultibase => write!(f, "unsupported multibase prefix"),
+            Self::UnsupportedVersion => write!(f, "unsupported CID version"),
+            Self::UnsupportedMultihash => write!(f, "unsupported multihash"),
+            Self::InvalidVarint => write!(f, "invalid varint"),
+        }
+    }
+}
+
+impl core::error::Error for ParseMultiformatError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base58_round_trip() {
+        for (digest, s) in [
+            (Digest([0; 32]), "11111111111111111111111111111111"),
+            (
+                Digest([0xee; 32]),
+                "H5hM4fqRjygvCYXnp6dgFLgZ6o4uJ8Q9z7dAsTfapHmF",
+            ),
+        ] {
+            assert_eq!(digest.to_base58().as_str(), s);
+            assert_eq!(Digest::from_base58(s).unwrap(), digest);
+        }
+    }
+
+    #[test]
+    fn base58_errors() {
+        assert_eq!(
+            Digest::from_base58("H5hM4fqRjygvCYXnp6dgFLgZ6o4uJ8Q9z7dAsTfapHm0"),
+            Err(ParseMultiformatError::InvalidBase58Character { c: '0', index: 43 }),
+        );
+        assert_eq!(
+            Digest::from_base58("H5hM4fqRjygvCYXnp6dgFLgZ6o4"),
+            Err(ParseMultiformatError::InvalidLength),
+        );
+    }
+
+    #[test]
+    fn multihash_errors() {
+        let mut multihash = Digest::default().to_multihash();
+        assert_eq!(
+            Digest::from_multihash(&multihash[..33]),
+            Err(ParseMultiformatError::InvalidLength),
+        );
+        multihash[0] = 0x12;
+        assert_eq!(
+            Digest::from_multihash(&multihash),
+            Err(ParseMultiformatError::UnsupportedMultihash),
+        );
+    }
+
+    #[test]
+    fn cid_round_trip() {
+        let cid = Cid::new(0x90, Digest([0xee; 32]));
+        let s = "z43AaGF8oVpCwmJ9ZR4kQKaSvd36VsBY9kCLDXdp4jpCy17qzGd";
+        assert_eq!(cid.to_string(), s);
+        assert_eq!(s.parse::<Cid>().unwrap(), cid);
+
+        let cid = Cid::new(Cid::MAX_CODEC, Digest([0xee; 32]));
+        assert_eq!(cid.to_bytes().len(), CID_MAX_LEN);
+        assert_eq!(cid.to_string().parse::<Cid>().unwrap(), cid);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cid_codec_out_of_range() {
+        let _ = Cid::new(Cid::MAX_CODEC + 1, Digest([0xee; 32]));
+    }
+
+    #[test]
+    fn cid_errors() {
+        assert_eq!(
+            "bafkr4ih".parse::<Cid>(),
+            Err(ParseMultiformatError::UnsupportedMultibase),
+        );
+        assert_eq!(
+            Cid::from_bytes(&[0x12, 0x20]),
+            Err(ParseMultiformatError::UnsupportedVersion),
+        );
+        assert_eq!(
+            Cid::from_bytes(&[0x01, 0x80, 0x80]),
+            Err(ParseMultiformatError::InvalidVarint),
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Module implementing a macro for defining strongly-typed digest newtypes.
+
+/// Defines strongly-typed digest newtypes.
+///
+/// Digests are used for many different purposes (transaction hashes, block
+/// hashes, state roots, etc.). This macro generates newtypes around [`Digest`]
+/// so that different kinds of digests cannot accidentally be mixed up, while
+/// still providing the same formatting, parsing, conversion and (with the
+/// `serde` feature) serialization implementations as [`Digest`].
+///
+/// Newtypes dereference to [`Digest`], so all of its methods are available.
+///
+/// [`Digest`]: crate::Digest
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{define_digest_type, Digest};
+/// define_digest_type! {
+///     /// A transaction hash.
+///     pub struct TxHash;
+///
+///     /// A state trie root.
+///     pub struct StateRoot;
+/// }
+///
+/// let hash = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee"
+///     .parse::<TxHash>()
+///     .unwrap();
+/// assert_eq!(hash, TxHash(Digest([0xee; 32])));
+/// assert_eq!(hash.to_string(), Digest([0xee; 32]).to_string());
+/// assert!(!hash.is_zero());
+///
+/// let root = StateRoot::from(Digest::from(hash));
+/// assert_eq!(root, StateRoot::new([0xee; 32]));
+/// ```
+///
+/// Different digest types cannot be compared with each other:
+///
+/// ```compile_fail
+/// # use ethdigest::define_digest_type;
+/// define_digest_type! {
+///     pub struct TxHash;
+///     pub struct StateRoot;
+/// }
+///
+/// assert_eq!(TxHash::default(), StateRoot::default());
+/// ```
+#[macro_export]
+macro_rules! define_digest_type {
+    ($($(#[$attr:meta])* $vis:vis struct $name:ident;)*) => {$(
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        $vis struct $name(pub $crate::Digest);
+
+        impl $name {
+            /// Creates a new digest from a 32-byte array. This can be used in
+            /// `const` contexts.
+            pub const fn new(bytes: [u8; 32]) -> Self {
+                Self($crate::Digest(bytes))
+            }
+
+            /// Returns the underlying untyped digest.
+            pub const fn into_digest(self) -> $crate::Digest {
+                self.0
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.debug_tuple(::core::stringify!($name))
+                    .field(&::core::format_args!("{}", self.0))
+                    .finish()
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::fmt::UpperHex for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::UpperHex::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::ParseDigestError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+
+        impl ::core::ops::Deref for $name {
+            type Target = $crate::Digest;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl ::core::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl ::core::convert::AsRef<$crate::Digest> for $name {
+            fn as_ref(&self) -> &$crate::Digest {
+                &self.0
+            }
+        }
+
+        impl ::core::convert::AsRef<[u8; 32]> for $name {
+            fn as_ref(&self) -> &[u8; 32] {
+                &self.0 .0
+            }
+        }
+
+        impl ::core::convert::AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0 .0
+            }
+        }
+
+        impl ::core::convert::From<$crate::Digest> for $name {
+            fn from(digest: $crate::Digest) -> Self {
+                Self(digest)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::Digest {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl ::core::convert::From<[u8; 32]> for $name {
+            fn from(bytes: [u8; 32]) -> Self {
+                Self::new(bytes)
+            }
+        }
+
+        impl ::core::convert::From<$name> for [u8; 32] {
+            fn from(value: $name) -> Self {
+                value.0 .0
+            }
+        }
+
+        impl ::core::convert::TryFrom<&'_ [u8]> for $name {
+            type Error = ::core::array::TryFromSliceError;
+
+            fn try_from(value: &'_ [u8]) -> ::core::result::Result<Self, Self::Error> {
+                $crate::Digest::try_from(value).map(Self)
+            }
+        }
+
+        $crate::__impl_digest_type_serde!($name);
+    )*};
+}
+
+/// Implements serialization for a digest newtype by delegating to [`Digest`].
+///
+/// [`Digest`]: crate::Digest
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_digest_type_serde {
+    ($name:ident) => {
+        impl<'de> $crate::__serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__serde::Deserializer<'de>,
+            {
+                <$crate::Digest as $crate::__serde::Deserialize<'de>>::deserialize(deserializer)
+                    .map(Self)
+            }
+        }
+
+        impl $crate::__serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__serde::Serializer,
+            {
+                $crate::__serde::Serialize::serialize(&self.0, serializer)
+            }
+        }
+    };
+}
+
+/// Serialization is only implemented with the `serde` feature.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_digest_type_serde {
+    ($name:ident) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Digest;
+
+    define_digest_type! {
+        /// A test digest.
+        struct TestHash;
+    }
+
+    #[test]
+    fn newtype_impls() {
+        let hash = TestHash::new([0xee; 32]);
+        assert_eq!(
+            format!("{hash:?}"),
+            "TestHash(0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee)",
+        );
+        assert_eq!(format!("{hash:X}"), format!("{:X}", hash.0));
+        assert_eq!(hash.to_string().parse::<TestHash>().unwrap(), hash);
+        assert_eq!(TestHash::try_from(&[0xee; 32][..]).unwrap(), hash);
+        assert!(TestHash::try_from(&[0xee; 31][..]).is_err());
+        assert_eq!(Digest::from(hash), Digest([0xee; 32]));
+        assert_eq!(<[u8; 32]>::from(hash), [0xee; 32]);
+        assert_eq!(hash.into_digest().to_selector(), [0xee; 4]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn newtype_serde() {
+        use serde::{
+            de::value::{self, BorrowedStrDeserializer},
+            Deserialize,
+        };
+
+        let s = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
+        let deserializer = BorrowedStrDeserializer::<value::Error>::new(s);
+        assert_eq!(
+            TestHash::deserialize(deserializer).unwrap(),
+            TestHash::new([0xee; 32]),
+        );
+    }
+}
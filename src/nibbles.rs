@@ -0,0 +1,232 @@
+//! Module implementing nibble paths for Merkle-Patricia trie keys.
+
+use crate::Digest;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::Range,
+};
+
+/// A path of nibbles (half-bytes) into a digest, as used for traversing
+/// Merkle-Patricia tries keyed by hashes.
+///
+/// A nibble path is a contiguous range of the 64 nibbles of a digest, and
+/// paths are compared nibble by nibble regardless of which digest or offset
+/// they originate from.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Digest;
+/// let key = Digest::ZERO.with_byte(0, 0xab).with_byte(1, 0xcd);
+/// let path = key.nibble_path();
+/// assert_eq!(path.len(), 64);
+///
+/// let (prefix, rest) = path.split_at(3);
+/// assert_eq!(prefix.to_string(), "abc");
+/// assert!(path.starts_with(&prefix));
+/// assert_eq!(path.strip_prefix(&prefix), Some(rest));
+/// assert_eq!(rest.get(0), Some(0xd));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Nibbles {
+    digest: Digest,
+    start: u8,
+    end: u8,
+}
+
+impl Digest {
+    /// Returns the nibble path of all 64 nibbles of the digest. See
+    /// [`Digest::nibbles`] for iterating over nibbles directly.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let path = Digest([0xee; 32]).nibble_path();
+    /// assert_eq!(path.len(), 64);
+    /// assert!(path.iter().all(|nibble| nibble == 0xe));
+    /// ```
+    pub fn nibble_path(&self) -> Nibbles {
+        Nibbles {
+            digest: *self,
+            start: 0,
+            end: 64,
+        }
+    }
+}
+
+impl Nibbles {
+    /// Returns the number of nibbles in the path.
+    pub fn len(&self) -> usize {
+        usize::from(self.end - self.start)
+    }
+
+    /// Returns whether or not the path is empty.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the nibble at the specified index, or `None` if it is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<u8> {
+        (index < self.len()).then(|| self.nibble(usize::from(self.start) + index))
+    }
+
+    /// Returns an iterator over the nibbles of the path.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + '_ {
+        (usize::from(self.start)..usize::from(self.end)).map(|i| self.nibble(i))
+    }
+
+    /// Returns a sub-path for the specified range of nibbles.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the range is out of bounds.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        assert!(
+            range.start <= range.end && range.end <= self.len(),
+            "nibble range out of bounds",
+        );
+        let start = usize::from(self.start);
+        Self {
+            digest: self.digest,
+            start: (start + range.start) as u8,
+            end: (start + range.end) as u8,
+        }
+    }
+
+    /// Splits the path into two at the specified index.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the index is greater than the path length.
+    pub fn split_at(&self, mid: usize) -> (Self, Self) {
+        (self.slice(0..mid), self.slice(mid..self.len()))
+    }
+
+    /// Returns the length of the longest common prefix of two paths.
+    pub fn common_prefix_len(&self, other: &Self) -> usize {
+        self.iter()
+            .zip(other.iter())
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Returns whether or not the path starts with the specified prefix.
+    pub fn starts_with(&self, prefix: &Self) -> bool {
+        prefix.len() <= self.len() && self.common_prefix_len(prefix) == prefix.len()
+    }
+
+    /// Returns the remainder of the path after the specified prefix, or `None`
+    /// if the path does not start with it.
+    pub fn strip_prefix(&self, prefix: &Self) -> Option<Self> {
+        self.starts_with(prefix)
+            .then(|| self.slice(prefix.len()..self.len()))
+    }
+
+    /// Returns the nibble at the specified absolute index into the digest.
+    fn nibble(&self, index: usize) -> u8 {
+        (self.digest[index / 2] >> (4 * (1 - index % 2))) & 0xf
+    }
+}
+
+impl From<Digest> for Nibbles {
+    fn from(digest: Digest) -> Self {
+        digest.nibble_path()
+    }
+}
+
+impl Debug for Nibbles {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Nibbles")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+impl Display for Nibbles {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for nibble in self.iter() {
+            write!(f, "{nibble:x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq for Nibbles {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for Nibbles {}
+
+impl PartialOrd for Nibbles {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Nibbles {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl Hash for Nibbles {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for nibble in self.iter() {
+            nibble.hash(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_compare_by_nibbles() {
+        let a = Digest::ZERO.with_byte(0, 0x12).with_byte(1, 0x34);
+        let b = Digest::ZERO.with_byte(0, 0x23).with_byte(1, 0x40);
+
+        // `a[1..3]` and `b[0..2]` are both `23`, but at different offsets.
+        let x = a.nibble_path().slice(1..3);
+        let y = b.nibble_path().slice(0..2);
+        assert_eq!(x, y);
+        assert_eq!(x.to_string(), "23");
+        assert_eq!(format!("{x:?}"), "Nibbles(23)");
+
+        let z = b.nibble_path().slice(0..3);
+        assert!(x < z);
+        assert_eq!(x.common_prefix_len(&z), 2);
+        assert!(z.starts_with(&x));
+        assert!(!x.starts_with(&z));
+        assert_eq!(z.strip_prefix(&x).unwrap().to_string(), "4");
+        assert_eq!(x.strip_prefix(&z), None);
+    }
+
+    #[test]
+    fn empty_paths() {
+        let path = Digest([0xee; 32]).nibble_path();
+        let (empty, rest) = path.split_at(0);
+        assert!(empty.is_empty());
+        assert_eq!(rest, path);
+        assert_eq!(empty.get(0), None);
+        assert_eq!(path.slice(64..64), empty);
+        assert_eq!(path.strip_prefix(&empty), Some(path));
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_out_of_bounds() {
+        Digest::ZERO.nibble_path().slice(1..3).slice(0..3);
+    }
+}
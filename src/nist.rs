@@ -0,0 +1,73 @@
+//! Module implementing the NIST SHA-3 hashers.
+//!
+//! These differ from the original Keccak hashers used by Ethereum only in
+//! their padding, which includes a domain separation suffix.
+
+use crate::{
+    sponge::{impl_hasher, Sponge},
+    Digest,
+};
+
+/// The SHA-3 domain separation suffix and first padding bit.
+const SHA3_DOMAIN: u8 = 0x06;
+
+/// A NIST SHA3-256 hasher.
+///
+/// Note that Ethereum uses the original Keccak-256 padding (see
+/// [`Keccak`](crate::Keccak)), so this will produce different digests for the
+/// same input.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, Sha3_256};
+/// assert_eq!(
+///     Sha3_256::new().chain_update("").finalize(),
+///     Digest::from_hex("0xa7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"),
+/// );
+/// assert_ne!(Sha3_256::new().finalize(), Digest::of(""));
+/// ```
+#[derive(Clone)]
+pub struct Sha3_256(Sponge<136>);
+
+impl_hasher!(Sha3_256, SHA3_DOMAIN, Digest, Digest::ZERO);
+
+/// A NIST SHA3-512 hasher.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Sha3_512;
+/// let output = Sha3_512::new().chain_update("").finalize();
+/// assert_eq!(output[..4], [0xa6, 0x9f, 0x73, 0xcc]);
+/// ```
+#[derive(Clone)]
+pub struct Sha3_512(Sponge<72>);
+
+impl_hasher!(Sha3_512, SHA3_DOMAIN, [u8; 64], [0; 64]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::Digest as _;
+
+    #[test]
+    fn matches_reference_implementation() {
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        for len in [0, 1, 71, 72, 73, 135, 136, 137, 1000] {
+            let data = &data[..len];
+            assert_eq!(
+                Sha3_256::new().chain_update(data).finalize()[..],
+                sha3::Sha3_256::digest(data)[..],
+            );
+            assert_eq!(
+                Sha3_512::new().chain_update(data).finalize()[..],
+                sha3::Sha3_512::digest(data)[..],
+            );
+        }
+    }
+}
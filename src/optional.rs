@@ -0,0 +1,100 @@
+//! Module implementing optional digests, where the all-zero digest represents
+//! an absent value.
+
+use crate::Digest;
+
+/// A digest where the all-zero digest represents an absent value.
+///
+/// Many Ethereum structures use the all-zero digest to mean that a value is
+/// not set (for example, the parent hash of a genesis block). This wrapper
+/// encodes that convention, and is serialized as `null` when zero with the
+/// `serde` feature.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, OptionalDigest};
+/// assert_eq!(OptionalDigest(Digest::ZERO).get(), None);
+/// assert_eq!(
+///     OptionalDigest(Digest([0xee; 32])).get(),
+///     Some(Digest([0xee; 32])),
+/// );
+/// assert_eq!(OptionalDigest::from(None), OptionalDigest::NONE);
+/// ```
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OptionalDigest(pub Digest);
+
+impl OptionalDigest {
+    /// The absent optional digest.
+    pub const NONE: Self = Self(Digest::ZERO);
+
+    /// Returns the digest, or `None` if it is zero.
+    pub fn get(&self) -> Option<Digest> {
+        self.0.into_option()
+    }
+
+    /// Returns whether or not the digest is absent.
+    pub fn is_none(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns whether or not the digest is present.
+    pub fn is_some(&self) -> bool {
+        !self.is_none()
+    }
+}
+
+impl Digest {
+    /// Converts the digest into an option, where the all-zero digest maps to
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::ZERO.into_option(), None);
+    /// assert_eq!(Digest([0xee; 32]).into_option(), Some(Digest([0xee; 32])));
+    /// ```
+    pub fn into_option(self) -> Option<Self> {
+        (!self.is_zero()).then_some(self)
+    }
+
+    /// Converts an option into a digest, where `None` maps to the all-zero
+    /// digest.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(Digest::from_option(None), Digest::ZERO);
+    /// assert_eq!(Digest::from_option(Some(Digest([0xee; 32]))), Digest([0xee; 32]));
+    /// ```
+    pub fn from_option(option: Option<Self>) -> Self {
+        option.unwrap_or(Self::ZERO)
+    }
+}
+
+impl From<Digest> for OptionalDigest {
+    fn from(value: Digest) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Option<Digest>> for OptionalDigest {
+    fn from(value: Option<Digest>) -> Self {
+        Self(Digest::from_option(value))
+    }
+}
+
+impl From<OptionalDigest> for Option<Digest> {
+    fn from(value: OptionalDigest) -> Self {
+        value.get()
+    }
+}
@@ -0,0 +1,169 @@
+//! Module implementing proof-of-work target and difficulty helpers.
+
+use crate::Digest;
+
+impl Digest {
+    /// Returns whether or not the digest meets a proof-of-work target, that
+    /// is, if it is less than or equal to the target when interpreted as a
+    /// 256-bit big-endian integer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let target = Digest::ZERO.with_byte(1, 0x10);
+    /// assert!(Digest::ZERO.with_byte(1, 0x0f).meets_target(&target));
+    /// assert!(target.meets_target(&target));
+    /// assert!(!Digest::ZERO.with_byte(0, 0x01).meets_target(&target));
+    /// ```
+    pub fn meets_target(&self, target: &Digest) -> bool {
+        self <= target
+    }
+
+    /// Computes the proof-of-work target for a difficulty, that is
+    /// `2^256 / difficulty`. Returns `None` for a zero difficulty.
+    ///
+    /// Since `2^256` does not fit in a digest, a difficulty of 1 saturates to
+    /// a target of [`Digest::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let difficulty = Digest::from_low_u64_be(0x10000);
+    /// let target = Digest::from_difficulty(&difficulty).unwrap();
+    /// assert_eq!(target, Digest::ZERO.with_byte(1, 0x01));
+    /// assert_eq!(target.to_difficulty(), Some(difficulty));
+    ///
+    /// assert_eq!(Digest::from_difficulty(&Digest::from_low_u64_be(1)), Some(Digest::MAX));
+    /// assert_eq!(Digest::from_difficulty(&Digest::ZERO), None);
+    /// ```
+    pub fn from_difficulty(difficulty: &Digest) -> Option<Self> {
+        pow_quotient(difficulty)
+    }
+
+    /// Computes the difficulty for a proof-of-work target, that is
+    /// `2^256 / target`. Returns `None` for a zero target.
+    ///
+    /// This is the inverse of [`Digest::from_difficulty`], and saturates to
+    /// [`Digest::MAX`] in the same way.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let target = Digest::ZERO.with_byte(2, 0x01);
+    /// assert_eq!(target.to_difficulty(), Some(Digest::from_low_u64_be(0x1000000)));
+    /// ```
+    pub fn to_difficulty(&self) -> Option<Digest> {
+        pow_quotient(self)
+    }
+}
+
+/// Computes `2^256 / divisor`, saturating to [`Digest::MAX`] on overflow.
+fn pow_quotient(divisor: &Digest) -> Option<Digest> {
+    if divisor.is_zero() {
+        return None;
+    }
+
+    // 2^256 is one more than the maximum digest, so the quotient needs to be
+    // incremented when that extra one completes another multiple of the
+    // divisor.
+    let (quotient, remainder) = div_rem(&Digest::MAX, divisor);
+    if remainder.wrapping_add(Digest::from_low_u64_be(1)) == *divisor {
+        Some(
+            quotient
+                .checked_add(Digest::from_low_u64_be(1))
+                .unwrap_or(Digest::MAX),
+        )
+    } else {
+        Some(quotient)
+    }
+}
+
+/// Divides two 256-bit big-endian integers with bitwise long division,
+/// returning the quotient and remainder. The divisor must be non-zero.
+fn div_rem(dividend: &Digest, divisor: &Digest) -> (Digest, Digest) {
+    let mut quotient = Digest::ZERO;
+    let mut remainder = Digest::ZERO;
+    for i in 0..256 {
+        // The remainder is always less than the divisor, so shifting it can
+        // overflow by at most one bit, in which case it is definitely larger
+        // than the divisor.
+        let overflow = remainder.bit(0);
+        remainder = shl1(&remainder);
+        remainder.set_bit(255, dividend.bit(i));
+        if overflow || remainder >= *divisor {
+            remainder = remainder.wrapping_sub(*divisor);
+            quotient.set_bit(i, true);
+        }
+    }
+    (quotient, remainder)
+}
+
+/// Shifts a 256-bit big-endian integer left by one bit.
+fn shl1(value: &Digest) -> Digest {
+    let mut words = value.to_le_words();
+    let mut carry = 0;
+    for word in &mut words {
+        let next = *word >> 63;
+        *word = (*word << 1) | carry;
+        carry = next;
+    }
+    Digest::from_le_words(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_division() {
+        let (n, d) = (
+            0x1234_5678_9abc_def0_1122_3344_5566_7788_u128,
+            0xfedc_ba98_7654_3210_u128,
+        );
+        let (quotient, remainder) = div_rem(
+            &Digest::from_be_u128_pair((0, n)),
+            &Digest::from_be_u128_pair((0, d)),
+        );
+        assert_eq!(quotient.to_be_u128_pair(), (0, n / d));
+        assert_eq!(remainder.to_be_u128_pair(), (0, n % d));
+
+        let (quotient, remainder) = div_rem(&Digest::MAX, &Digest::MAX);
+        assert_eq!(quotient, Digest::from_low_u64_be(1));
+        assert_eq!(remainder, Digest::ZERO);
+    }
+
+    #[test]
+    fn difficulty_round_trips() {
+        for difficulty in [2, 3, 7, 1 << 20, 12_345_678_901, u64::MAX] {
+            let difficulty = Digest::from_low_u64_be(difficulty);
+            let target = Digest::from_difficulty(&difficulty).unwrap();
+            let inverse = target.to_difficulty().unwrap();
+            // Rounding means that the round trip is only exact for powers of
+            // two, but it never results in an easier target.
+            assert!(inverse >= difficulty);
+            assert!(Digest::from_difficulty(&inverse).unwrap() <= target);
+        }
+
+        assert_eq!(
+            Digest::from_difficulty(&Digest::from_low_u64_be(2)),
+            Some(Digest::ZERO.with_byte(0, 0x80)),
+        );
+        assert_eq!(
+            Digest::from_difficulty(&Digest::from_low_u64_be(3)),
+            Some(Digest([0x55; 32])),
+        );
+        assert_eq!(
+            Digest::MAX.to_difficulty(),
+            Some(Digest::from_low_u64_be(1))
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Outputs of the EVM hashing precompiles.
+//!
+//! This allows EVM emulation code to compute the outputs of the `SHA256`,
+//! `RIPEMD160` and `IDENTITY` precompiles through a single consistent API.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! # use ethdigest::{precompiles, Digest};
+//! assert_eq!(precompiles::sha256(b"abc"), Digest::sha256_of(b"abc"));
+//! assert_eq!(precompiles::ripemd160(b"abc")[..12], [0; 12]);
+//! assert_eq!(precompiles::identity(b"abc"), b"abc");
+//! ```
+
+use crate::Digest;
+use ::ripemd::{Digest as _, Ripemd160};
+
+/// The address of the `SHA256` precompile.
+pub const SHA256_ADDRESS: [u8; 20] = address(0x02);
+
+/// The address of the `RIPEMD160` precompile.
+pub const RIPEMD160_ADDRESS: [u8; 20] = address(0x03);
+
+/// The address of the `IDENTITY` precompile.
+pub const IDENTITY_ADDRESS: [u8; 20] = address(0x04);
+
+/// Returns the address of a precompile from its index.
+const fn address(index: u8) -> [u8; 20] {
+    let mut address = [0; 20];
+    address[19] = index;
+    address
+}
+
+/// Computes the output of the `SHA256` precompile.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{precompiles, Digest};
+/// assert_eq!(
+///     precompiles::sha256([]),
+///     Digest::from_hex("0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+/// );
+/// ```
+pub fn sha256(input: impl AsRef<[u8]>) -> Digest {
+    Digest::sha256_of(input)
+}
+
+/// Computes the output of the `RIPEMD160` precompile. This is the 20-byte
+/// RIPEMD-160 hash left-padded with zeros to 32 bytes.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{precompiles, Digest};
+/// assert_eq!(
+///     precompiles::ripemd160([]),
+///     Digest::from_hex("0x0000000000000000000000009c1185a5c5e9fc54612808977ee8f548b2258d31"),
+/// );
+/// ```
+pub fn ripemd160(input: impl AsRef<[u8]>) -> Digest {
+    let mut digest = Digest::ZERO;
+    digest[12..].copy_from_slice(&Ripemd160::digest(input));
+    digest
+}
+
+/// Computes the output of the `IDENTITY` precompile, which is its input.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::precompiles;
+/// assert_eq!(precompiles::identity(b"Hello Ethereum!"), b"Hello Ethereum!");
+/// ```
+pub fn identity(input: &[u8]) -> &[u8] {
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ripemd160_vectors() {
+        assert_eq!(
+            ripemd160(b"abc"),
+            Digest::from_hex("0x0000000000000000000000008eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+        );
+        assert_eq!(
+            ripemd160(b"message digest"),
+            Digest::from_hex("0x0000000000000000000000005d0689ef49d2fae572b881b123a85ffa21595f36"),
+        );
+    }
+
+    #[test]
+    fn precompile_addresses() {
+        assert_eq!(SHA256_ADDRESS[..19], [0; 19]);
+        assert_eq!(
+            [
+                SHA256_ADDRESS[19],
+                RIPEMD160_ADDRESS[19],
+                IDENTITY_ADDRESS[19]
+            ],
+            [2, 3, 4],
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Module implementing random digest generation with the `rand` crate.
+
+use crate::Digest;
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+impl Digest {
+    /// Generates a random digest using the thread-local random number
+    /// generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_ne!(Digest::random(), Digest::random());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn random() -> Self {
+        Self::random_with(&mut rand::thread_rng())
+    }
+
+    /// Generates a random digest using the specified random number generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// # use rand::{rngs::mock::StepRng, Rng};
+    /// let mut rng = StepRng::new(0, 0);
+    /// assert_eq!(Digest::random_with(&mut rng), Digest::ZERO);
+    /// ```
+    pub fn random_with(rng: &mut (impl Rng + ?Sized)) -> Self {
+        rng.gen()
+    }
+}
+
+impl Distribution<Digest> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Digest {
+        let mut digest = Digest::ZERO;
+        rng.fill_bytes(&mut digest.0);
+        digest
+    }
+}
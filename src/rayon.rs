@@ -0,0 +1,43 @@
+//! Module implementing parallel hashing with the `rayon` crate.
+
+use crate::Digest;
+use ::rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::vec::Vec;
+
+impl Digest {
+    /// Hashes many inputs in parallel on the global [`rayon`] thread
+    /// pool, returning their digests in the same order as the inputs.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// let inputs = vec![vec![0_u8; 100], vec![1; 200], vec![2; 300]];
+    /// assert_eq!(
+    ///     Digest::of_par_iter(&inputs),
+    ///     inputs.iter().map(Digest::of).collect::<Vec<_>>(),
+    /// );
+    /// ```
+    pub fn of_par_iter<I>(items: I) -> Vec<Digest>
+    where
+        I: IntoParallelIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        items.into_par_iter().map(Digest::of).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_hashing_preserves_order() {
+        let digests = Digest::of_par_iter((0..10_000_u32).into_par_iter().map(u32::to_be_bytes));
+        for (i, digest) in digests.into_iter().enumerate() {
+            assert_eq!(digest, Digest::of((i as u32).to_be_bytes()));
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::{
     buffer::{self, Alphabet},
-    Digest,
+    Digest, OptionalDigest, PrefixPolicy,
 };
 use core::fmt::{self, Formatter};
 use serde::{
@@ -33,10 +33,7 @@ impl<'de> Visitor<'de> for DigestVisitor {
     where
         E: de::Error,
     {
-        s.strip_prefix("0x")
-            .ok_or_else(|| de::Error::custom("missing `0x`-prefix"))?
-            .parse()
-            .map_err(de::Error::custom)
+        Digest::from_str_with(s, PrefixPolicy::Required).map_err(de::Error::custom)
     }
 }
 
@@ -50,10 +47,69 @@ impl Serialize for Digest {
     }
 }
 
+impl<'de> Deserialize<'de> for OptionalDigest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionalDigestVisitor)
+    }
+}
+
+struct OptionalDigestVisitor;
+
+impl<'de> Visitor<'de> for OptionalDigestVisitor {
+    type Value = OptionalDigest;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a `0x`-prefixed 32-byte hex string or null")
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(OptionalDigest::NONE)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(OptionalDigest::NONE)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Digest::deserialize(deserializer).map(OptionalDigest)
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        DigestVisitor.visit_str(s).map(OptionalDigest)
+    }
+}
+
+impl Serialize for OptionalDigest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.get() {
+            Some(digest) => serializer.serialize_some(&digest),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde::de::value::{self, BorrowedStrDeserializer};
+    use serde::de::value::{self, BorrowedStrDeserializer, UnitDeserializer};
 
     #[test]
     fn deserialize_digest() {
@@ -76,4 +132,20 @@ mod tests {
         let deserializer = BorrowedStrDeserializer::<value::Error>::new(without_prefix);
         assert!(Digest::deserialize(deserializer).is_err());
     }
+
+    #[test]
+    fn deserialize_optional_digest() {
+        let deserializer = UnitDeserializer::<value::Error>::new();
+        assert_eq!(
+            OptionalDigest::deserialize(deserializer).unwrap(),
+            OptionalDigest::NONE,
+        );
+
+        let s = "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee";
+        let deserializer = BorrowedStrDeserializer::<value::Error>::new(s);
+        assert_eq!(
+            OptionalDigest::deserialize(deserializer).unwrap(),
+            OptionalDigest(Digest([0xee; 32])),
+        );
+    }
 }
@@ -0,0 +1,102 @@
+//! Module implementing SHA-256 hashing of digests.
+
+use crate::Digest;
+use ::sha2::Digest as _;
+use core::fmt::{self, Debug, Formatter};
+
+impl Digest {
+    /// Creates a digest by hashing some input data with SHA-256.
+    ///
+    /// This is used by the SHA-256 precompile, EIP-4844 versioned hashes and
+    /// beacon chain deposits, among others.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use ethdigest::Digest;
+    /// assert_eq!(
+    ///     Digest::sha256_of(""),
+    ///     Digest::from_hex("0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+    /// );
+    /// ```
+    pub fn sha256_of(data: impl AsRef<[u8]>) -> Self {
+        Sha256::new().chain_update(data).finalize()
+    }
+}
+
+/// A SHA-256 hasher.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, Sha256};
+/// let mut hasher = Sha256::new();
+/// hasher.update("Hello ");
+/// hasher.update("Ethereum!");
+/// assert_eq!(hasher.finalize(), Digest::sha256_of("Hello Ethereum!"));
+/// ```
+#[derive(Clone, Default)]
+pub struct Sha256(::sha2::Sha256);
+
+impl Sha256 {
+    /// Creates a new [`Sha256`] instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Processes new data and updates the hasher.
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.0.update(data);
+    }
+
+    /// Processes new data and returns the updated hasher.
+    #[must_use]
+    pub fn chain_update(mut self, data: impl AsRef<[u8]>) -> Self {
+        self.update(data);
+        self
+    }
+
+    /// Retrieve the resulting digest.
+    pub fn finalize(self) -> Digest {
+        Digest(self.0.finalize().into())
+    }
+
+    /// Retrieve the resulting digest and reset the hasher to its initial
+    /// state, so that it can be reused.
+    pub fn finalize_reset(&mut self) -> Digest {
+        Digest(self.0.finalize_reset().into())
+    }
+
+    /// Resets the hasher to its initial state, discarding any processed
+    /// data.
+    pub fn reset(&mut self) {
+        ::sha2::Digest::reset(&mut self.0);
+    }
+}
+
+impl Debug for Sha256 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Sha256").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256_hasher_reuse() {
+        let mut hasher = Sha256::new();
+        for _ in 0..2 {
+            hasher.update([0xee; 200]);
+            assert_eq!(hasher.finalize_reset(), Digest::sha256_of([0xee; 200]));
+        }
+        hasher.update("discarded");
+        hasher.reset();
+        assert_eq!(hasher.finalize(), Digest::sha256_of([]));
+    }
+}
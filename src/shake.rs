@@ -0,0 +1,187 @@
+//! Module implementing the SHAKE extendable-output functions.
+
+use crate::sponge::{self, Sponge};
+use core::fmt::{self, Debug, Formatter};
+
+/// The SHAKE domain separation suffix and first padding bit.
+pub const SHAKE_DOMAIN: u8 = 0x1f;
+
+macro_rules! impl_shake {
+    ($name:ident, $rate:expr) => {
+        impl $name {
+            /// Creates a new hasher instance.
+            pub fn new() -> Self {
+                Self(Sponge::new())
+            }
+
+            /// Processes new data and updates the hasher.
+            pub fn update(&mut self, data: impl AsRef<[u8]>) {
+                self.0.update(data.as_ref());
+            }
+
+            /// Processes new data and returns the updated hasher.
+            #[must_use]
+            pub fn chain_update(mut self, data: impl AsRef<[u8]>) -> Self {
+                self.update(data);
+                self
+            }
+
+            /// Finalizes the hasher and returns a reader for an arbitrary
+            /// amount of output.
+            pub fn finalize_xof(mut self) -> ShakeReader {
+                self.finalize_xof_reset()
+            }
+
+            /// Finalizes the hasher, returning a reader for an arbitrary amount
+            /// of output, and resets the hasher to its initial state so that it
+            /// can be reused.
+            pub fn finalize_xof_reset(&mut self) -> ShakeReader {
+                self.0.pad(SHAKE_DOMAIN);
+                let reader = ShakeReader::new(self.0.state, $rate);
+                self.reset();
+                reader
+            }
+
+            /// Resets the hasher to its initial state, discarding any processed
+            /// data.
+            pub fn reset(&mut self) {
+                *self = Self::new();
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Debug for $name {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).finish()
+            }
+        }
+    };
+}
+
+/// A SHAKE128 extendable-output hasher.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Shake128;
+/// let mut output = [0; 4];
+/// Shake128::new().chain_update("").finalize_xof().read(&mut output);
+/// assert_eq!(output, [0x7f, 0x9c, 0x2b, 0xa4]);
+/// ```
+#[derive(Clone)]
+pub struct Shake128(Sponge<168>);
+
+impl_shake!(Shake128, 168);
+
+/// A SHAKE256 extendable-output hasher.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::Shake256;
+/// let mut reader = Shake256::new().chain_update("").finalize_xof();
+/// let (mut a, mut b) = ([0; 2], [0; 2]);
+/// reader.read(&mut a);
+/// reader.read(&mut b);
+/// assert_eq!([a, b], [[0x46, 0xb9], [0xdd, 0x2b]]);
+/// ```
+#[derive(Clone)]
+pub struct Shake256(Sponge<136>);
+
+impl_shake!(Shake256, 136);
+
+/// A reader for the output of a finalized SHAKE hasher.
+///
+/// This is created with [`Shake128::finalize_xof`] or
+/// [`Shake256::finalize_xof`] (as well as their cSHAKE and KMAC
+/// counterparts), and can produce an arbitrary amount of output over
+/// successive reads.
+#[derive(Clone)]
+pub struct ShakeReader {
+    state: [u64; 25],
+    rate: usize,
+    pos: usize,
+}
+
+impl ShakeReader {
+    /// Creates a new reader from a padded sponge state.
+    pub(crate) fn new(state: [u64; 25], rate: usize) -> Self {
+        Self {
+            state,
+            rate,
+            pos: 0,
+        }
+    }
+
+    /// Fills the buffer with the next output bytes.
+    pub fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer {
+            if self.pos == self.rate {
+                sponge::permute(&mut self.state);
+                self.pos = 0;
+            }
+            *byte = (self.state[self.pos / 8] >> (8 * (self.pos % 8))) as u8;
+            self.pos += 1;
+        }
+    }
+}
+
+impl Debug for ShakeReader {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("ShakeReader").finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for ShakeReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        ShakeReader::read(self, buf);
+        Ok(buf.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::digest::{ExtendableOutput, Update, XofReader};
+
+    #[test]
+    fn matches_reference_implementation() {
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        for len in [0, 1, 135, 136, 137, 167, 168, 169, 1000] {
+            let data = &data[..len];
+
+            // Read in uneven chunks to exercise squeezing across blocks.
+            let mut ours = [0; 500];
+            let mut reader = Shake128::new().chain_update(data).finalize_xof();
+            for chunk in ours.chunks_mut(77) {
+                reader.read(chunk);
+            }
+            let mut expected = [0; 500];
+            sha3::Shake128::default()
+                .chain(data)
+                .finalize_xof()
+                .read(&mut expected);
+            assert_eq!(ours, expected);
+
+            let mut reader = Shake256::new().chain_update(data).finalize_xof();
+            for chunk in ours.chunks_mut(77) {
+                reader.read(chunk);
+            }
+            sha3::Shake256::default()
+                .chain(data)
+                .finalize_xof()
+                .read(&mut expected);
+            assert_eq!(ours, expected);
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//! Internal module implementing SIMD accelerated hex encoding and decoding.
+//!
+//! On x86 and x86-64, there is an SSSE3 baseline and an AVX2 path that
+//! processes twice as many characters per instruction. On AArch64, NEON is
+//! used. Support is detected at runtime when the `std` feature is enabled, and
+//! at compile time otherwise.
+
+/// Returns whether or not SIMD hex encoding and decoding is available.
+#[inline]
+pub fn available() -> bool {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        ssse3::available()
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        neon::available()
+    }
+}
+
+/// Encodes 32 bytes into 64 hex characters using the specified nibble
+/// lookup-table.
+///
+/// # Safety
+///
+/// The caller must ensure that SIMD support is [`available`] and that `out` is
+/// valid for writing 64 bytes.
+#[inline]
+pub unsafe fn encode(bytes: &[u8; 32], lut: &[u8; 16], out: *mut u8) {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if avx2::available() {
+        avx2::encode(bytes, lut, out);
+    } else {
+        ssse3::encode(bytes, lut, out);
+    }
+    #[cfg(target_arch = "aarch64")]
+    neon::encode(bytes, lut, out);
+}
+
+/// Decodes 64 hex characters into 32 bytes. Returns `None` if any of the
+/// characters are not valid hex digits.
+///
+/// # Safety
+///
+/// The caller must ensure that SIMD support is [`available`].
+#[inline]
+pub unsafe fn decode(hex: &[u8; 64]) -> Option<[u8; 32]> {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if avx2::available() {
+        avx2::decode(hex)
+    } else {
+        ssse3::decode(hex)
+    }
+    #[cfg(target_arch = "aarch64")]
+    neon::decode(hex)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod ssse3 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// Returns whether or not SSSE3 is available.
+    #[inline]
+    pub fn available() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("ssse3")
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "ssse3")
+        }
+    }
+
+    /// Encodes 32 bytes into 64 hex characters.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that SSSE3 is available and that `out` is valid
+    /// for writing 64 bytes.
+    #[target_feature(enable = "ssse3")]
+    pub unsafe fn encode(bytes: &[u8; 32], lut: &[u8; 16], out: *mut u8) {
+        let lut = _mm_loadu_si128(lut.as_ptr().cast());
+        let mask = _mm_set1_epi8(0x0f);
+        for i in 0..2 {
+            let input = _mm_loadu_si128(bytes.as_ptr().add(i * 16).cast());
+            let hi = _mm_shuffle_epi8(lut, _mm_and_si128(_mm_srli_epi16(input, 4), mask));
+            let lo = _mm_shuffle_epi8(lut, _mm_and_si128(input, mask));
+            _mm_storeu_si128(out.add(i * 32).cast(), _mm_unpacklo_epi8(hi, lo));
+            _mm_storeu_si128(out.add(i * 32 + 16).cast(), _mm_unpackhi_epi8(hi, lo));
+        }
+    }
+
+    /// Decodes 64 hex characters into 32 bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that SSSE3 is available.
+    #[target_feature(enable = "ssse3")]
+    pub unsafe fn decode(hex: &[u8; 64]) -> Option<[u8; 32]> {
+        let mut bytes = [0_u8; 32];
+        // Multiplying adjacent nibbles by 16 and 1 and adding them together
+        // computes the byte value.
+        let weights = _mm_set1_epi16(0x0110);
+        for i in 0..2 {
+            let hi = nibbles(_mm_loadu_si128(hex.as_ptr().add(i * 32).cast()))?;
+            let lo = nibbles(_mm_loadu_si128(hex.as_ptr().add(i * 32 + 16).cast()))?;
+            let packed = _mm_packus_epi16(
+                _mm_maddubs_epi16(hi, weights),
+                _mm_maddubs_epi16(lo, weights),
+            );
+            _mm_storeu_si128(bytes.as_mut_ptr().add(i * 16).cast(), packed);
+        }
+        Some(bytes)
+    }
+
+    /// Converts 16 hex characters into their nibble values.
+    #[target_feature(enable = "ssse3")]
+    unsafe fn nibbles(c: __m128i) -> Option<__m128i> {
+        let digit = _mm_sub_epi8(c, _mm_set1_epi8(b'0' as _));
+        let is_digit = _mm_cmpeq_epi8(_mm_min_epu8(digit, _mm_set1_epi8(9)), digit);
+
+        // Setting the `0x20` bit maps upper-case letters to lower-case ones.
+        let letter = _mm_sub_epi8(
+            _mm_or_si128(c, _mm_set1_epi8(0x20)),
+            _mm_set1_epi8(b'a' as _),
+        );
+        let is_letter = _mm_cmpeq_epi8(_mm_min_epu8(letter, _mm_set1_epi8(5)), letter);
+
+        if _mm_movemask_epi8(_mm_or_si128(is_digit, is_letter)) != 0xffff {
+            return None;
+        }
+        Some(_mm_or_si128(
+            _mm_and_si128(is_digit, digit),
+            _mm_and_si128(is_letter, _mm_add_epi8(letter, _mm_set1_epi8(10))),
+        ))
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// Returns whether or not AVX2 is available.
+    #[inline]
+    pub fn available() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::is_x86_feature_detected!("avx2")
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "avx2")
+        }
+    }
+
+    /// Encodes 32 bytes into 64 hex characters.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that AVX2 is available and that `out` is valid
+    /// for writing 64 bytes.
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn encode(bytes: &[u8; 32], lut: &[u8; 16], out: *mut u8) {
+        let lut = _mm256_broadcastsi128_si256(_mm_loadu_si128(lut.as_ptr().cast()));
+        let mask = _mm256_set1_epi8(0x0f);
+        let input = _mm256_loadu_si256(bytes.as_ptr().cast());
+        let hi = _mm256_shuffle_epi8(lut, _mm256_and_si256(_mm256_srli_epi16(input, 4), mask));
+        let lo = _mm256_shuffle_epi8(lut, _mm256_and_si256(input, mask));
+
+        // Unpacking interleaves within 128-bit lanes, so the first half of the
+        // output is in the low lanes and the second half in the high lanes.
+        let first = _mm256_unpacklo_epi8(hi, lo);
+        let second = _mm256_unpackhi_epi8(hi, lo);
+        _mm256_storeu_si256(out.cast(), _mm256_permute2x128_si256(first, second, 0x20));
+        _mm256_storeu_si256(
+            out.add(32).cast(),
+            _mm256_permute2x128_si256(first, second, 0x31),
+        );
+    }
+
+    /// Decodes 64 hex characters into 32 bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that AVX2 is available.
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn decode(hex: &[u8; 64]) -> Option<[u8; 32]> {
+        let mut bytes = [0_u8; 32];
+        // Multiplying adjacent nibbles by 16 and 1 and adding them together
+        // computes the byte value.
+        let weights = _mm256_set1_epi16(0x0110);
+        let hi = nibbles(_mm256_loadu_si256(hex.as_ptr().cast()))?;
+        let lo = nibbles(_mm256_loadu_si256(hex.as_ptr().add(32).cast()))?;
+
+        // Packing works within 128-bit lanes, leaving the 8-byte groups in
+        // the order 0, 2, 1, 3.
+        let packed = _mm256_packus_epi16(
+            _mm256_maddubs_epi16(hi, weights),
+            _mm256_maddubs_epi16(lo, weights),
+        );
+        _mm256_storeu_si256(
+            bytes.as_mut_ptr().cast(),
+            _mm256_permute4x64_epi64(packed, 0b11_01_10_00),
+        );
+        Some(bytes)
+    }
+
+    /// Converts 32 hex characters into their nibble values.
+    #[target_feature(enable = "avx2")]
+    unsafe fn nibbles(c: __m256i) -> Option<__m256i> {
+        let digit = _mm256_sub_epi8(c, _mm256_set1_epi8(b'0' as _));
+        let is_digit = _mm256_cmpeq_epi8(_mm256_min_epu8(digit, _mm256_set1_epi8(9)), digit);
+
+        // Setting the `0x20` bit maps upper-case letters to lower-case ones.
+        let letter = _mm256_sub_epi8(
+            _mm256_or_si256(c, _mm256_set1_epi8(0x20)),
+            _mm256_set1_epi8(b'a' as _),
+        );
+        let is_letter = _mm256_cmpeq_epi8(_mm256_min_epu8(letter, _mm256_set1_epi8(5)), letter);
+
+        if _mm256_movemask_epi8(_mm256_or_si256(is_digit, is_letter)) != -1 {
+            return None;
+        }
+        Some(_mm256_or_si256(
+            _mm256_and_si256(is_digit, digit),
+            _mm256_and_si256(is_letter, _mm256_add_epi8(letter, _mm256_set1_epi8(10))),
+        ))
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use core::arch::aarch64::*;
+
+    /// Returns whether or not NEON is available.
+    #[inline]
+    pub fn available() -> bool {
+        #[cfg(feature = "std")]
+        {
+            std::arch::is_aarch64_feature_detected!("neon")
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = "neon")
+        }
+    }
+
+    /// Encodes 32 bytes into 64 hex characters.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that NEON is available and that `out` is valid
+    /// for writing 64 bytes.
+    #[target_feature(enable = "neon")]
+    pub unsafe fn encode(bytes: &[u8; 32], lut: &[u8; 16], out: *mut u8) {
+        let lut = vld1q_u8(lut.as_ptr());
+        let mask = vdupq_n_u8(0x0f);
+        for i in 0..2 {
+            let input = vld1q_u8(bytes.as_ptr().add(i * 16));
+            let hi = vqtbl1q_u8(lut, vshrq_n_u8::<4>(input));
+            let lo = vqtbl1q_u8(lut, vandq_u8(input, mask));
+            // Interleaving stores write the high and low nibble characters of
+            // each byte next to each other.
+            vst2q_u8(out.add(i * 32), uint8x16x2_t(hi, lo));
+        }
+    }
+
+    /// Decodes 64 hex characters into 32 bytes.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that NEON is available.
+    #[target_feature(enable = "neon")]
+    pub unsafe fn decode(hex: &[u8; 64]) -> Option<[u8; 32]> {
+        let mut bytes = [0_u8; 32];
+        for i in 0..2 {
+            // De-interleaving loads split the high and low nibble characters.
+            let chars = vld2q_u8(hex.as_ptr().add(i * 32));
+            let hi = nibbles(chars.0)?;
+            let lo = nibbles(chars.1)?;
+            vst1q_u8(
+                bytes.as_mut_ptr().add(i * 16),
+                vorrq_u8(vshlq_n_u8::<4>(hi), lo),
+            );
+        }
+        Some(bytes)
+    }
+
+    /// Converts 16 hex characters into their nibble values.
+    #[target_feature(enable = "neon")]
+    unsafe fn nibbles(c: uint8x16_t) -> Option<uint8x16_t> {
+        let digit = vsubq_u8(c, vdupq_n_u8(b'0'));
+        let is_digit = vcleq_u8(digit, vdupq_n_u8(9));
+
+        // Setting the `0x20` bit maps upper-case letters to lower-case ones.
+        let letter = vsubq_u8(vorrq_u8(c, vdupq_n_u8(0x20)), vdupq_n_u8(b'a'));
+        let is_letter = vcleq_u8(letter, vdupq_n_u8(5));
+
+        if vminvq_u8(vorrq_u8(is_digit, is_letter)) != 0xff {
+            return None;
+        }
+        Some(vbslq_u8(is_digit, digit, vaddq_u8(letter, vdupq_n_u8(10))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LUT: &[u8; 16] = b"0123456789abcdef";
+
+    fn inputs() -> impl Iterator<Item = [u8; 32]> {
+        (0..=255_u8).map(|i| {
+            let mut bytes = [0; 32];
+            for (j, byte) in bytes.iter_mut().enumerate() {
+                *byte = i.wrapping_mul(31).wrapping_add((j as u8).wrapping_mul(97));
+            }
+            bytes
+        })
+    }
+
+    fn scalar_encode(bytes: &[u8; 32]) -> [u8; 64] {
+        let mut hex = [0; 64];
+        for (i, byte) in bytes.iter().enumerate() {
+            hex[i * 2] = LUT[(byte >> 4) as usize];
+            hex[i * 2 + 1] = LUT[(byte & 0xf) as usize];
+        }
+        hex
+    }
+
+    type Encode = unsafe fn(&[u8; 32], &[u8; 16], *mut u8);
+    type Decode = unsafe fn(&[u8; 64]) -> Option<[u8; 32]>;
+
+    /// Returns the available implementations to test.
+    fn implementations() -> impl Iterator<Item = (Encode, Decode)> {
+        [
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            (
+                ssse3::available(),
+                ssse3::encode as Encode,
+                ssse3::decode as Decode,
+            ),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            (avx2::available(), avx2::encode, avx2::decode),
+            #[cfg(target_arch = "aarch64")]
+            (
+                neon::available(),
+                neon::encode as Encode,
+                neon::decode as Decode,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(available, encode, decode)| available.then_some((encode, decode)))
+    }
+
+    #[test]
+    fn encode_matches_scalar() {
+        for (encode, _) in implementations() {
+            for bytes in inputs() {
+                let mut hex = [0; 64];
+                unsafe { encode(&bytes, LUT, hex.as_mut_ptr()) };
+                assert_eq!(hex, scalar_encode(&bytes));
+            }
+        }
+    }
+
+    #[test]
+    fn decode_matches_scalar() {
+        for (_, decode) in implementations() {
+            for bytes in inputs() {
+                let hex = scalar_encode(&bytes);
+                assert_eq!(unsafe { decode(&hex) }, Some(bytes));
+                let upper = hex.map(|c| c.to_ascii_uppercase());
+                assert_eq!(unsafe { decode(&upper) }, Some(bytes));
+            }
+        }
+    }
+
+    #[test]
+    fn decode_rejects_invalid_characters() {
+        for (_, decode) in implementations() {
+            for c in 0..=255_u8 {
+                if c.is_ascii_hexdigit() {
+                    continue;
+                }
+                for i in 0..64 {
+                    let mut hex = [b'0'; 64];
+                    hex[i] = c;
+                    assert_eq!(unsafe { decode(&hex) }, None);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,325 @@
+//! Internal module implementing the Keccak-f\[1600\] sponge construction shared
+//! by the Keccak family of hashers.
+
+/// A Keccak-f\[1600\] sponge absorbing `RATE` bytes per permutation.
+#[derive(Clone)]
+pub struct Sponge<const RATE: usize> {
+    /// The Keccak-f\[1600\] state lanes.
+    pub state: [u64; 25],
+    /// Pending input that has not yet been absorbed into the state.
+    pub buffer: [u8; RATE],
+    /// The total number of bytes processed.
+    pub len: u64,
+}
+
+impl<const RATE: usize> Sponge<RATE> {
+    /// Creates a new empty sponge.
+    pub const fn new() -> Self {
+        Self {
+            state: [0; 25],
+            buffer: [0; RATE],
+            len: 0,
+        }
+    }
+
+    /// Absorbs new data into the sponge.
+    pub fn update(&mut self, mut data: &[u8]) {
+        let pos = self.pos();
+        self.len += data.len() as u64;
+
+        if pos > 0 {
+            let n = data.len().min(RATE - pos);
+            self.buffer[pos..][..n].copy_from_slice(&data[..n]);
+            if pos + n < RATE {
+                return;
+            }
+            absorb(&mut self.state, &self.buffer);
+            data = &data[n..];
+        }
+
+        let mut blocks = data.chunks_exact(RATE);
+        for block in &mut blocks {
+            absorb(&mut self.state, block);
+        }
+        let remainder = blocks.remainder();
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+    }
+
+    /// Returns the position of pending input in the buffer.
+    pub fn pos(&self) -> usize {
+        (self.len % RATE as u64) as usize
+    }
+
+    /// Pads the pending input with the specified domain separation suffix and
+    /// absorbs it, leaving the state ready for squeezing.
+    pub fn pad(&mut self, domain: u8) {
+        let pos = self.pos();
+        self.buffer[pos..].fill(0);
+        self.buffer[pos] ^= domain;
+        self.buffer[RATE - 1] ^= 0x80;
+        absorb(&mut self.state, &self.buffer);
+    }
+
+    /// Copies output bytes from the start of the state. The output must not
+    /// be longer than the rate.
+    pub fn squeeze(&self, out: &mut [u8]) {
+        debug_assert!(out.len() <= RATE);
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = (self.state[i / 8] >> (8 * (i % 8))) as u8;
+        }
+    }
+}
+
+/// Absorbs a block of input into the sponge state.
+fn absorb(state: &mut [u64; 25], block: &[u8]) {
+    for (lane, chunk) in state.iter_mut().zip(block.chunks_exact(8)) {
+        *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    permute(state);
+}
+
+/// Implements the common methods and traits of a fixed output size hasher
+/// wrapping a [`Sponge`], padded with the specified domain byte.
+macro_rules! impl_hasher {
+    ($name:ident, $domain:expr, $output:ty, $zero:expr) => {
+        impl $name {
+            /// Creates a new hasher instance.
+            pub fn new() -> Self {
+                Self($crate::sponge::Sponge::new())
+            }
+
+            /// Processes new data and updates the hasher.
+            pub fn update(&mut self, data: impl AsRef<[u8]>) {
+                self.0.update(data.as_ref());
+            }
+
+            /// Processes new data and returns the updated hasher.
+            #[must_use]
+            pub fn chain_update(mut self, data: impl AsRef<[u8]>) -> Self {
+                self.update(data);
+                self
+            }
+
+            /// Retrieve the resulting output.
+            pub fn finalize(mut self) -> $output {
+                self.finalize_reset()
+            }
+
+            /// Retrieve the resulting output and reset the hasher to its
+            /// initial state, so that it can be reused.
+            pub fn finalize_reset(&mut self) -> $output {
+                let mut output: $output = $zero;
+                self.0.pad($domain);
+                self.0.squeeze(&mut output[..]);
+                self.reset();
+                output
+            }
+
+            /// Resets the hasher to its initial state, discarding any processed
+            /// data.
+            pub fn reset(&mut self) {
+                *self = Self::new();
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name)).finish()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::io::Write for $name {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.update(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_hasher;
+
+/// Applies the Keccak-f\[1600\] permutation to the sponge state.
+///
+/// The implementation is selected by crate features: the CRYPTOGAMS assembly
+/// with `asm`, the `tiny-keccak` crate with `tiny-keccak`, the RustCrypto
+/// `keccak` crate with `rustcrypto`, and otherwise the dependency-free
+/// implementation in this crate.
+#[cfg(feature = "asm")]
+pub fn permute(state: &mut [u64; 25]) {
+    // The assembly only exposes the SHA-3 absorb and squeeze functions, so
+    // permute by absorbing a single lane of zeros, which leaves the state
+    // unchanged before applying the permutation.
+    ::sha3_asm::sha3_absorb(state, &[0; 8], 8);
+}
+
+/// Applies the Keccak-f\[1600\] permutation to the sponge state.
+#[cfg(all(feature = "tiny-keccak", not(feature = "asm")))]
+pub fn permute(state: &mut [u64; 25]) {
+    ::tiny_keccak::keccakf(state);
+}
+
+/// Applies the Keccak-f\[1600\] permutation to the sponge state.
+#[cfg(all(
+    feature = "rustcrypto",
+    not(any(feature = "asm", feature = "tiny-keccak"))
+))]
+pub fn permute(state: &mut [u64; 25]) {
+    ::keccak::f1600(state);
+}
+
+/// Applies the Keccak-f\[1600\] permutation to the sponge state.
+///
+/// On x86 and x86-64, a variant compiled with BMI1 and BMI2 (which provide
+/// single instruction and-not and rotations) is used when available. Support is
+/// detected once at runtime when the `std` feature is enabled, and at compile
+/// time otherwise.
+#[cfg(not(any(feature = "asm", feature = "tiny-keccak", feature = "rustcrypto")))]
+pub fn permute(state: &mut [u64; 25]) {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    // SAFETY: Only permutations supported by the CPU are selected.
+    unsafe {
+        x86::selected()(state);
+    }
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    portable(state);
+}
+
+/// Applies the dependency-free Keccak-f\[1600\] permutation to a state.
+#[cfg(not(any(feature = "asm", feature = "tiny-keccak", feature = "rustcrypto")))]
+fn portable(state: &mut [u64; 25]) {
+    *state = crate::keccakf::f1600(*state);
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(any(feature = "asm", feature = "tiny-keccak", feature = "rustcrypto"))
+))]
+mod x86 {
+    use core::{
+        mem, ptr,
+        sync::atomic::{AtomicPtr, Ordering},
+    };
+
+    /// A Keccak-f\[1600\] permutation variant, which may require CPU
+    /// features.
+    pub type Permutation = unsafe fn(&mut [u64; 25]);
+
+    /// Returns the fastest permutation supported by the CPU.
+    ///
+    /// The selection is cached so that CPU features are only detected once
+    /// instead of for every block.
+    #[inline]
+    pub fn selected() -> Permutation {
+        static SELECTED: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+        let selected = SELECTED.load(Ordering::Relaxed);
+        if !selected.is_null() {
+            // SAFETY: The pointer was cast from a `Permutation` below.
+            return unsafe { mem::transmute::<*mut (), Permutation>(selected) };
+        }
+
+        let selected: Permutation = if bmi::available() {
+            bmi::permute
+        } else {
+            super::portable
+        };
+        SELECTED.store(selected as *mut (), Ordering::Relaxed);
+        selected
+    }
+
+    pub mod bmi {
+        /// Returns whether or not BMI1 and BMI2 are available.
+        #[inline]
+        pub fn available() -> bool {
+            #[cfg(feature = "std")]
+            {
+                std::is_x86_feature_detected!("bmi1") && std::is_x86_feature_detected!("bmi2")
+            }
+            #[cfg(not(feature = "std"))]
+            {
+                cfg!(all(target_feature = "bmi1", target_feature = "bmi2"))
+            }
+        }
+
+        /// Applies the Keccak-f\[1600\] permutation to a state, compiled
+        /// with BMI1 and BMI2 instructions.
+        ///
+        /// # Safety
+        ///
+        /// The caller must ensure that BMI1 and BMI2 are available.
+        #[target_feature(enable = "bmi1,bmi2")]
+        pub unsafe fn permute(state: &mut [u64; 25]) {
+            *state = crate::keccakf::f1600(*state);
+        }
+    }
+}
+
+#[cfg(all(
+    test,
+    not(any(feature = "asm", feature = "tiny-keccak", feature = "rustcrypto"))
+))]
+mod tests {
+    use super::*;
+    use sha3::Digest as _;
+
+    type Permutation = unsafe fn(&mut [u64; 25]);
+
+    /// Returns the available permutations to test.
+    fn permutations() -> impl Iterator<Item = Permutation> {
+        [
+            (true, portable as Permutation),
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            (x86::bmi::available(), x86::bmi::permute),
+        ]
+        .into_iter()
+        .filter_map(|(available, permute)| available.then_some(permute))
+    }
+
+    /// Computes a Keccak-256 hash with a specific permutation.
+    fn keccak256(permute: Permutation, data: &[u8]) -> [u8; 32] {
+        let mut padded = data.to_vec();
+        padded.push(0x01);
+        padded.resize(padded.len().next_multiple_of(136), 0);
+        *padded.last_mut().unwrap() |= 0x80;
+
+        let mut state = [0_u64; 25];
+        for block in padded.chunks_exact(136) {
+            for (lane, chunk) in state.iter_mut().zip(block.chunks_exact(8)) {
+                *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+            }
+            // SAFETY: Only available permutations are tested.
+            unsafe { permute(&mut state) };
+        }
+
+        let mut digest = [0; 32];
+        for (chunk, lane) in digest.chunks_exact_mut(8).zip(&state) {
+            chunk.copy_from_slice(&lane.to_le_bytes());
+        }
+        digest
+    }
+
+    #[test]
+    fn permutations_match_sha3() {
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        for permute in permutations() {
+            for len in [0, 1, 135, 136, 137, 272, 1000] {
+                assert_eq!(
+                    keccak256(permute, &data[..len]),
+                    <[u8; 32]>::from(sha3::Keccak256::digest(&data[..len])),
+                );
+            }
+        }
+    }
+}
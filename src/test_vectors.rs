@@ -0,0 +1,151 @@
+//! Canonical Keccak-256 test vectors.
+//!
+//! These can be used by downstream crates and alternative hashing backends for
+//! validating their implementations.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! # #[cfg(feature = "keccak")] {
+//! # use ethdigest::{test_vectors, Digest, Keccak};
+//! for vector in test_vectors::all() {
+//!     let mut hasher = Keccak::new();
+//!     hasher.extend(vector.input());
+//!     assert_eq!(hasher.finalize(), vector.digest, "{}", vector.name);
+//! }
+//! # }
+//! ```
+
+use crate::Digest;
+use core::iter::{self, Repeat, Take};
+
+/// A Keccak-256 test vector.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TestVector {
+    /// A short descriptive name of the test vector.
+    pub name: &'static str,
+    /// The input chunk, repeated [`TestVector::repeat`] times.
+    pub chunk: &'static [u8],
+    /// The number of times the input chunk is repeated.
+    pub repeat: usize,
+    /// The expected Keccak-256 digest of the input.
+    pub digest: Digest,
+}
+
+impl TestVector {
+    /// Returns an iterator over the chunks of the test vector input. This
+    /// allows long inputs to be hashed without allocating them.
+    pub fn input(&self) -> Take<Repeat<&'static [u8]>> {
+        iter::repeat(self.chunk).take(self.repeat)
+    }
+
+    /// Returns the total length of the test vector input in bytes.
+    pub fn len(&self) -> usize {
+        self.chunk.len() * self.repeat
+    }
+
+    /// Returns whether or not the test vector input is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The empty input.
+pub const EMPTY: TestVector = vector(
+    "empty",
+    b"",
+    1,
+    "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+);
+
+/// The NIST `"abc"` input.
+pub const ABC: TestVector = vector(
+    "abc",
+    b"abc",
+    1,
+    "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+);
+
+/// The NIST 448-bit input.
+pub const NIST_448: TestVector = vector(
+    "nist-448",
+    b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+    1,
+    "45d3b367a6904e6e8d502ee04999a7c27647f91fa845d456525fd352ae3d7371",
+);
+
+/// The NIST 896-bit input.
+pub const NIST_896: TestVector = vector(
+    "nist-896",
+    b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+    1,
+    "f519747ed599024f3882238e5ab43960132572b7345fbeb9a90769dafd21ad67",
+);
+
+/// The well-known pangram.
+pub const QUICK_BROWN_FOX: TestVector = vector(
+    "quick-brown-fox",
+    b"The quick brown fox jumps over the lazy dog",
+    1,
+    "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15",
+);
+
+/// The ERC-20 `transfer` function signature, whose digest prefix is the
+/// well-known `0xa9059cbb` selector.
+pub const ERC20_TRANSFER: TestVector = vector(
+    "erc20-transfer",
+    b"transfer(address,uint256)",
+    1,
+    "a9059cbb2ab09eb219583f4a59a5d0623ade346d962bcd4e46b11da047c9049b",
+);
+
+/// An input of exactly one Keccak-256 block (136 bytes).
+pub const ONE_BLOCK: TestVector = vector(
+    "one-block",
+    b"a",
+    136,
+    "a6c4d403279fe3e0af03729caada8374b5ca54d8065329a3ebcaeb4b60aa386e",
+);
+
+/// The NIST one million `a` input.
+pub const ONE_MILLION_A: TestVector = vector(
+    "one-million-a",
+    b"a",
+    1_000_000,
+    "fadae6b49f129bbb812be8407b7b2894f34aecf6dbd1f9b0f0c7e9853098fc96",
+);
+
+/// All test vectors.
+pub const ALL: &[TestVector] = &[
+    EMPTY,
+    ABC,
+    NIST_448,
+    NIST_896,
+    QUICK_BROWN_FOX,
+    ERC20_TRANSFER,
+    ONE_BLOCK,
+    ONE_MILLION_A,
+];
+
+/// Returns an iterator over all test vectors.
+pub fn all() -> impl Iterator<Item = TestVector> {
+    ALL.iter().copied()
+}
+
+/// Creates a test vector, parsing the expected digest from a hex string at
+/// compile time.
+const fn vector(
+    name: &'static str,
+    chunk: &'static [u8],
+    repeat: usize,
+    digest: &str,
+) -> TestVector {
+    TestVector {
+        name,
+        chunk,
+        repeat,
+        digest: Digest::from_hex(digest),
+    }
+}
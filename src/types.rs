@@ -0,0 +1,69 @@
+//! Strongly-typed digests for common Ethereum digest roles.
+//!
+//! These are defined with [`define_digest_type!`](crate::define_digest_type),
+//! so they can be used interchangeably across crates instead of each crate
+//! defining its own incompatible copies.
+//!
+//! # Examples
+//!
+//! Basic usage:
+//!
+//! ```
+//! # use ethdigest::{types::{BlockHash, TxHash}, Digest};
+//! let tx = TxHash::new([0xee; 32]);
+//! let block = BlockHash::from(Digest::from(tx));
+//! assert_eq!(tx.into_digest(), block.into_digest());
+//! ```
+
+use crate::Digest;
+
+crate::define_digest_type! {
+    /// A transaction hash.
+    pub struct TxHash;
+
+    /// A block hash.
+    pub struct BlockHash;
+
+    /// The hash of an account's contract code.
+    pub struct CodeHash;
+
+    /// A contract storage slot key.
+    pub struct StorageKey;
+
+    /// The root hash of a Merkle-Patricia trie, such as a block's state root.
+    pub struct StateRoot;
+}
+
+impl CodeHash {
+    /// The code hash of accounts without code, that is the Keccak-256 digest
+    /// of the empty input.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "keccak")] {
+    /// # use ethdigest::{types::CodeHash, Digest};
+    /// assert_eq!(CodeHash::EMPTY, CodeHash(Digest::of([])));
+    /// # }
+    /// ```
+    pub const EMPTY: Self = Self(Digest::KECCAK_EMPTY);
+}
+
+impl StateRoot {
+    /// The root hash of an empty trie, that is the Keccak-256 digest of the
+    /// RLP-encoded empty string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #[cfg(feature = "keccak")] {
+    /// # use ethdigest::{types::StateRoot, Digest};
+    /// assert_eq!(StateRoot::EMPTY, StateRoot(Digest::of([0x80])));
+    /// # }
+    /// ```
+    pub const EMPTY: Self = Self(Digest::EMPTY_ROOT_HASH);
+}
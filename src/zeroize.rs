@@ -0,0 +1,86 @@
+//! Module implementing memory zeroing of digests holding secret material.
+
+use crate::Digest;
+use core::fmt::{self, Debug, Formatter};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+impl Zeroize for Digest {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// A digest holding secret material, such as a key derivation output.
+///
+/// The digest is zeroed when the wrapper is dropped, and its value is redacted
+/// from [`Debug`] output. Unlike [`Digest`], this type is intentionally not
+/// [`Copy`] so that the secret is not implicitly duplicated.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use ethdigest::{Digest, SecretDigest};
+/// let secret = SecretDigest::new(Digest([0xee; 32]));
+/// assert_eq!(secret.expose_secret(), &Digest([0xee; 32]));
+/// assert_eq!(format!("{secret:?}"), "SecretDigest(<redacted>)");
+/// ```
+#[derive(Clone, Default)]
+pub struct SecretDigest(Digest);
+
+impl SecretDigest {
+    /// Creates a new secret digest.
+    pub fn new(digest: Digest) -> Self {
+        Self(digest)
+    }
+
+    /// Returns a reference to the secret digest value.
+    pub fn expose_secret(&self) -> &Digest {
+        &self.0
+    }
+}
+
+impl From<Digest> for SecretDigest {
+    fn from(digest: Digest) -> Self {
+        Self(digest)
+    }
+}
+
+impl Debug for SecretDigest {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("SecretDigest")
+            .field(&format_args!("<redacted>"))
+            .finish()
+    }
+}
+
+impl Zeroize for SecretDigest {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SecretDigest {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretDigest {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeroize_digest() {
+        let mut digest = Digest([0xee; 32]);
+        digest.zeroize();
+        assert_eq!(digest, Digest::ZERO);
+
+        let mut secret = SecretDigest::new(Digest([0xee; 32]));
+        secret.zeroize();
+        assert_eq!(secret.expose_secret(), &Digest::ZERO);
+    }
+}